@@ -24,6 +24,9 @@ Usage:
 import sys
 import argparse
 import json
+import signal
+import sqlite3
+from datetime import datetime
 from pathlib import Path
 
 # Add parent directory to path for imports
@@ -32,8 +35,13 @@ REPO_ROOT = SCRIPT_DIR.parent
 sys.path.insert(0, str(REPO_ROOT))
 
 try:
-    from src.messages_interface import MessagesInterface
-    from src.contacts_manager import ContactsManager
+    from src.messages_interface import MessagesInterface, pagination_token_decode
+    from src.contacts_manager import ContactsManager, Contact, SearchCriteria, ContactUpdate, CONTACTS_JSON_ENV
+    from src.snooze_store import SnoozeStore
+    from src.utils import (
+        is_likely_phone_number, format_bytes, sender_color, colorize,
+        summarize_text, truncate_to_sentence, bar_chart, heatmap_chart, notify, group_by_date
+    )
 except ImportError as e:
     print(f"Error: Could not import modules: {e}")
     print(f"Make sure you're running from the imessage-mcp repository root")
@@ -42,20 +50,68 @@ except ImportError as e:
 
 # Default config path (relative to repo root)
 CONTACTS_CONFIG = REPO_ROOT / "config" / "contacts.json"
+SNOOZES_CONFIG = REPO_ROOT / "config" / "followup_snoozes.json"
 
 # Valid RAG sources (single source of truth)
 VALID_RAG_SOURCES = ['imessage', 'superwhisper', 'notes', 'local', 'gmail', 'slack', 'calendar']
 
+# Shortest `watch --interval`; faster polling just keeps chat.db busy
+MIN_WATCH_INTERVAL = 0.5
+
+
+# Set by --verbose: called with each phase while chat.db is opened
+OPEN_PROGRESS = None
+
+# Set by --backup-dir: query the messages database in an iOS device backup
+BACKUP_DIR = None
+
+
+def print_open_progress(phase):
+    """Report a database opening phase on stderr (for --verbose)."""
+    print(f"[{phase.value}]", file=sys.stderr)
+
 
 def get_interfaces():
     """Initialize MessagesInterface and ContactsManager."""
-    mi = MessagesInterface()
-    cm = ContactsManager(str(CONTACTS_CONFIG))
+    if BACKUP_DIR:
+        try:
+            mi = MessagesInterface.open_backup(BACKUP_DIR, progress=OPEN_PROGRESS)
+        except (OSError, sqlite3.Error) as e:
+            # No fallback: silently reading the live chat.db would be misleading
+            print(f"Error opening backup: {e}", file=sys.stderr)
+            sys.exit(1)
+    elif OPEN_PROGRESS:
+        try:
+            mi = MessagesInterface.open_with_progress(progress=OPEN_PROGRESS)
+        except (OSError, sqlite3.Error) as e:
+            # Fall back so commands that don't need chat.db still run
+            print(f"Error opening Messages database: {e}", file=sys.stderr)
+            mi = MessagesInterface()
+    else:
+        mi = MessagesInterface()
+    cm = ContactsManager.load_from_env(str(CONTACTS_CONFIG))
     return mi, cm
 
 
+def contacts_writable(cm: ContactsManager) -> bool:
+    """Check a command may save contacts; injected contacts are read-only."""
+    if cm.read_only:
+        print(f"Error: contacts were loaded from {CONTACTS_JSON_ENV} and can't be changed. "
+              f"Unset it to edit {CONTACTS_CONFIG}.", file=sys.stderr)
+        return False
+    return True
+
+
 def resolve_contact(cm: ContactsManager, name: str):
-    """Resolve contact name to Contact object using fuzzy matching."""
+    """Resolve contact name to Contact object using fuzzy matching.
+
+    Inputs that look like phone numbers skip name matching and resolve to
+    the matching contact, or to an ad-hoc Contact for unknown numbers.
+    """
+    if is_likely_phone_number(name):
+        phone = name.strip().translate(str.maketrans('', '', ' ()-.'))
+        return cm.get_contact_by_phone(phone) or Contact(name=phone, phone=phone)
+
     contact = cm.get_contact_by_name(name)
     # get_contact_by_name already does partial matching
     if contact and contact.name.lower() != name.lower():
@@ -63,9 +119,158 @@ def resolve_contact(cm: ContactsManager, name: str):
     return contact
 
 
+def load_contact_list(cm: ContactsManager, path: str):
+    """Resolve a file of contact names/phones (one per line) to Contacts."""
+    contacts = []
+    for line in Path(path).expanduser().read_text().splitlines():
+        entry = line.strip()
+        if not entry or entry.startswith('#'):
+            continue
+        contact = resolve_contact(cm, entry)
+        if contact:
+            contacts.append(contact)
+        else:
+            print(f"Skipping '{entry}': contact not found.", file=sys.stderr)
+    return contacts
+
+
+def parse_date_arg(value: str, end_of_day: bool = False) -> datetime:
+    """Parse a YYYY-MM-DD (or full ISO) CLI date; end_of_day extends bare dates to 23:59:59."""
+    try:
+        date = datetime.fromisoformat(value)
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"invalid date '{value}' (expected YYYY-MM-DD)")
+    if end_of_day and len(value) == 10:
+        date = date.replace(hour=23, minute=59, second=59)
+    return date
+
+
+def watch_interval_arg(value: str) -> float:
+    """Parse `watch --interval`, rejecting polls faster than MIN_WATCH_INTERVAL."""
+    try:
+        interval = float(value)
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"invalid interval '{value}' (expected seconds)")
+    if not MIN_WATCH_INTERVAL <= interval < float('inf'):
+        raise argparse.ArgumentTypeError(f"interval must be at least {MIN_WATCH_INTERVAL} seconds")
+    return interval
+
+
+def add_color_args(parser):
+    """Add --color-by-sender and TTY override flags to a subcommand."""
+    parser.add_argument('--color-by-sender', action='store_true',
+                        help='Give each sender a consistent color')
+    color = parser.add_mutually_exclusive_group()
+    color.add_argument('--color', dest='color', action='store_true', default=None,
+                       help='Force colors even when output is not a terminal')
+    color.add_argument('--no-color', dest='color', action='store_false',
+                       help='Disable colors')
+
+
+def format_sender(args, sender: str) -> str:
+    """Colorize a sender label when --color-by-sender is active."""
+    if not getattr(args, 'color_by_sender', False):
+        return sender
+    enabled = args.color if args.color is not None else sys.stdout.isatty()
+    return colorize(sender, sender_color(sender)) if enabled else sender
+
+
+# ANSI colors for follow-up priority labels (Low stays uncolored)
+PRIORITY_COLORS = {"Urgent": 31, "High": 33, "Medium": 36}
+
+
+def format_priority(label: str) -> str:
+    """Render a follow-up priority label, colorized when stdout is a terminal."""
+    text = f"[{label}]"
+    color = PRIORITY_COLORS.get(label)
+    return colorize(text, color) if color and sys.stdout.isatty() else text
+
+
+def highlight_matches(text: str, positions) -> str:
+    """Embolden [start, end] spans of text when stdout is a terminal."""
+    if not positions or not sys.stdout.isatty():
+        return text
+    parts, last = [], 0
+    for start, end in positions:
+        parts += [text[last:start], colorize(text[start:end], 1)]
+        last = end
+    parts.append(text[last:])
+    return "".join(parts)
+
+
+def print_json_pretty(data):
+    """Print data as indented JSON."""
+    print(json.dumps(data, indent=2, default=str))
+
+
+def print_json_compact(data):
+    """Print data as single-line JSON with no extra whitespace."""
+    print(json.dumps(data, separators=(',', ':'), default=str))
+
+
+def print_json(args, data):
+    """Print JSON in the style picked by --json or --json-compact."""
+    if args.json == 'compact':
+        print_json_compact(data)
+    else:
+        print_json_pretty(data)
+
+
+def shorten(args, text: str, default: int, sentences: bool = False) -> str:
+    """Truncate text to --max-text-length, or the command's default.
+
+    With sentences=True the cut prefers a sentence boundary over a word one.
+    """
+    limit = args.max_text_length if args.max_text_length is not None else default
+    if sentences:
+        return truncate_to_sentence(text, limit)
+    return summarize_text(text, limit)
+
+
+# CLI spelling of find options, for errors about combinations a mode would ignore
+FIND_FLAG_NAMES = {
+    'query': '--query',
+    'contact_list': '--contact-list',
+    'groups_only': '--groups-only',
+    'date_from': '--from',
+    'date_to': '--to',
+}
+
+
+def _reject_ignored_find_flags(args, mode: str, dests) -> bool:
+    """Print an error and return True if any option in dests is set alongside mode."""
+    ignored = [FIND_FLAG_NAMES[dest] for dest in dests if getattr(args, dest)]
+    if ignored:
+        print(f"Error: {mode} cannot be combined with {', '.join(ignored)}", file=sys.stderr)
+    return bool(ignored)
+
+
 def cmd_find(args):
     """Find messages with a contact (keyword search)."""
+    if args.min_length is not None or args.max_length is not None:
+        if _reject_ignored_find_flags(args, "--min-length/--max-length",
+                                      ['query', 'contact_list', 'groups_only', 'date_from', 'date_to']):
+            return 1
+
+    for mode, active in (("--has-url", args.has_url), ("--proximity", args.proximity)):
+        if active and _reject_ignored_find_flags(args, mode, ['query', 'date_from', 'date_to']):
+            return 1
+
     mi, cm = get_interfaces()
+
+    if args.contact_list:
+        return _find_in_contact_list(mi, cm, args)
+
+    if args.groups_only:
+        return _find_in_groups(mi, args)
+
+    if args.min_length is not None or args.max_length is not None:
+        return _find_by_length(mi, cm, args)
+
+    if not args.contact:
+        print("Error: Must provide a contact or --contact-list", file=sys.stderr)
+        return 1
+
     contact = resolve_contact(cm, args.contact)
 
     if not contact:
@@ -73,9 +278,42 @@ def cmd_find(args):
         print("Available contacts:", ", ".join(c.name for c in cm.contacts[:10]), file=sys.stderr)
         return 1
 
+    if args.mentioned_contacts:
+        mentions = mi.contacts_mentioned_in_messages(contact.phone, cm, limit=args.limit)
+        if args.json:
+            print(json.dumps(mentions, indent=2, default=str))
+        else:
+            if not mentions:
+                print(f"No contacts mentioned in messages with {contact.name}.")
+                return 0
+            print(f"Contacts mentioned in messages with {contact.name}:")
+            print("-" * 60)
+            for m in mentions:
+                print(f"{m['name']}: {m['mention_count']} mentions")
+        return 0
+
     # Use efficient database-level search when query provided
-    if args.query:
-        messages = mi.search_messages(query=args.query, phone=contact.phone, limit=args.limit)
+    if args.has_url:
+        messages = mi.messages_with_urls(contact.phone, limit=args.limit)
+    elif args.proximity:
+        messages = mi.search_with_proximity(contact.phone, args.proximity,
+                                            max_distance=args.distance, limit=args.limit)
+    elif args.date_from or args.date_to:
+        if not args.query:
+            print("Error: --from/--to require --query", file=sys.stderr)
+            return 1
+        messages = mi.search_in_range(contact.phone, args.query,
+                                      start=args.date_from or datetime(2001, 1, 1),
+                                      end=args.date_to or datetime.now(),
+                                      limit=args.limit)
+    elif args.highlight:
+        if not args.query:
+            print("Error: --highlight requires --query", file=sys.stderr)
+            return 1
+        messages = mi.search_with_positions(contact.phone, args.query, limit=args.limit)
+    elif args.query:
+        messages = mi.search_messages(query=args.query, phone=contact.phone, limit=args.limit,
+                                      whole_word=args.whole_word)
     else:
         messages = mi.get_messages_by_phone(contact.phone, limit=args.limit)
 
@@ -89,7 +327,156 @@ def cmd_find(args):
             sender = "Me" if m.get('is_from_me') else contact.name
             text = m.get('text', '[media/attachment]') or '[media/attachment]'
             timestamp = m.get('timestamp', '')
-            print(f"{timestamp} | {sender}: {text[:200]}")
+            preview = shorten(args, text, 200)
+            visible = len(preview) if preview == text else len(preview) - 1
+            positions = [p for p in m.get('match_positions', []) if p[1] <= visible]
+            print(f"{timestamp} | {sender}: {highlight_matches(preview, positions)}")
+
+    return 0
+
+
+def _find_by_length(mi: MessagesInterface, cm: ContactsManager, args):
+    """List messages within a text length range, optionally for one contact."""
+    contact = None
+    if args.contact:
+        contact = resolve_contact(cm, args.contact)
+        if not contact:
+            print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+            return 1
+
+    messages = mi.messages_by_length(contact.phone if contact else None, min_len=args.min_length,
+                                     max_len=args.max_length, limit=args.limit)
+
+    if args.json:
+        print(json.dumps(messages, indent=2, default=str))
+    else:
+        bounds = []
+        if args.min_length is not None:
+            bounds.append(f"at least {args.min_length}")
+        if args.max_length is not None:
+            bounds.append(f"at most {args.max_length}")
+        scope = f" with {contact.name}" if contact else ""
+        print(f"Messages{scope} of {' and '.join(bounds)} characters:")
+        print("-" * 60)
+
+        for m in messages:
+            if m.get('is_from_me'):
+                sender = "Me"
+            else:
+                sender_contact = cm.get_contact_by_phone(m['phone'])
+                sender = sender_contact.name if sender_contact else m['phone']
+            print(f"[{(m.get('date') or '')[:16]}] {sender} ({m['length']} chars): {shorten(args, m['text'], 200)}")
+
+    return 0
+
+
+def _find_in_groups(mi: MessagesInterface, args):
+    """Keyword search across all group chats."""
+    if not args.query:
+        print("Error: --groups-only requires --query", file=sys.stderr)
+        return 1
+
+    messages = mi.search_in_groups(args.query, limit=args.limit)
+
+    if args.json:
+        print(json.dumps(messages, indent=2, default=str))
+    else:
+        print(f"Group messages matching \"{args.query}\":")
+        print("-" * 60)
+
+        for m in messages:
+            group = m.get('chat_display_name') or m.get('group_id')
+            sender = "Me" if m.get('is_from_me') else m.get('phone')
+            print(f"{m.get('date', '')} | {group} | {sender}: {shorten(args, m['text'], 200)}")
+
+    return 0
+
+
+def _find_in_contact_list(mi: MessagesInterface, cm: ContactsManager, args):
+    """Keyword search across every contact listed in a file."""
+    if not args.query:
+        print("Error: --contact-list requires --query", file=sys.stderr)
+        return 1
+
+    try:
+        contacts = load_contact_list(cm, args.contact_list)
+    except OSError as e:
+        print(f"Error reading contact list: {e}", file=sys.stderr)
+        return 1
+
+    messages = []
+    for contact in contacts:
+        for m in mi.search_messages(query=args.query, phone=contact.phone, limit=args.limit):
+            m['contact_name'] = contact.name
+            messages.append(m)
+
+    messages.sort(key=lambda m: m.get('date') or '', reverse=True)
+    messages = messages[:args.limit]
+
+    if args.json:
+        print(json.dumps(messages, indent=2, default=str))
+    else:
+        print(f"Messages matching \"{args.query}\" across {len(contacts)} contacts:")
+        print("-" * 60)
+
+        for m in messages:
+            sender = "Me" if m.get('is_from_me') else m.get('contact_name')
+            text = m.get('text', '[media/attachment]') or '[media/attachment]'
+            timestamp = m.get('date', '')
+            print(f"{timestamp} | {m.get('contact_name')} | {sender}: {shorten(args, text, 200)}")
+
+    return 0
+
+
+def export_text_transcript(messages, contact_name: str, path: str) -> int:
+    """
+    Write messages as a plain text transcript, oldest first.
+
+    Each line is "[HH:MM] Sender: text", with a blank line between days.
+
+    Returns:
+        Number of messages written
+    """
+    current_day = None
+    with open(Path(path).expanduser(), 'w', encoding='utf-8') as f:
+        for m in reversed(messages):
+            date = datetime.fromisoformat(m['date']) if m.get('date') else None
+            day = date.date() if date else None
+            if current_day is not None and day != current_day:
+                f.write("\n")
+            current_day = day
+
+            time_str = date.strftime("%H:%M") if date else "--:--"
+            sender = "Me" if m.get('is_from_me') else contact_name
+            text = m.get('text') or '[media]'
+            f.write(f"[{time_str}] {sender}: {text}\n")
+
+    return len(messages)
+
+
+def _print_timeline(mi, contact, args):
+    """Print messages oldest first with day and gap separators."""
+    timeline = mi.conversation_timeline(contact.phone, limit=args.limit)
+
+    if args.json:
+        print(json.dumps(timeline, indent=2, default=str))
+        return 0
+
+    if not timeline:
+        print("No messages found.")
+        return 0
+
+    for entry in timeline:
+        if entry['type'] == 'day_boundary':
+            print(f"\n--- {entry['label']} ---")
+        elif entry['type'] == 'gap_marker':
+            print(f"\n... {entry['label']} ...")
+        else:
+            m = entry['message']
+            sender = "Me" if m.get('is_from_me') else contact.name
+            time_str = m['date'][11:16] if m.get('date') else "--:--"
+            text = m.get('text', '[media]') or '[media]'
+            print(f"[{time_str}] {format_sender(args, sender)}: {shorten(args, text, 200)}")
 
     return 0
 
@@ -103,7 +490,53 @@ def cmd_messages(args):
         print(f"Contact '{args.contact}' not found.", file=sys.stderr)
         return 1
 
-    messages = mi.get_messages_by_phone(contact.phone, limit=args.limit)
+    if args.timeline:
+        return _print_timeline(mi, contact, args)
+
+    if args.first_received:
+        first = mi.first_received_message(contact.phone)
+        if args.json:
+            print(json.dumps(first, indent=2))
+        elif not first:
+            print(f"{contact.name} has never messaged you.")
+        else:
+            print(f"First message from {contact.name} ({first['date'][:10]}):")
+            print(first['text'])
+        return 0
+
+    if args.estimate_size:
+        estimate = mi.conversation_size_estimate(contact.phone)
+        if args.json:
+            print(json.dumps(estimate, indent=2))
+        else:
+            print(f"Conversation with {contact.name}:")
+            print("-" * 40)
+            print(f"Messages: {estimate['message_count']}")
+            print(f"Text: {format_bytes(estimate['text_bytes'])}")
+            print(f"Attachments: {estimate['attachment_refs_count']}")
+            print(f"Estimated memory to load: {format_bytes(estimate['estimated_ram_bytes'])}")
+        return 0
+
+    if args.export_csv:
+        with open(Path(args.export_csv).expanduser(), 'w', newline='', encoding='utf-8') as f:
+            count = mi.export_messages_csv(contact.phone, f, limit=args.limit)
+        print(f"Exported {count} messages to {args.export_csv}", file=sys.stderr)
+        return 0
+
+    if args.paged or args.page_token:
+        return _print_messages_page(mi, contact, args)
+
+    if args.sample:
+        messages = mi.random_messages(contact.phone, n=args.sample, fast=args.fast_sample)
+    elif args.include_reactions:
+        messages = mi.get_messages_with_reactions(contact.phone, limit=args.limit)
+    else:
+        messages = mi.get_messages_by_phone(contact.phone, limit=args.limit)
+
+    if args.export_text:
+        count = export_text_transcript(messages, contact.name, args.export_text)
+        print(f"Exported {count} messages to {args.export_text}", file=sys.stderr)
+        return 0
 
     if args.json:
         print(json.dumps(messages, indent=2, default=str))
@@ -112,18 +545,73 @@ def cmd_messages(args):
             print("No messages found.")
             return 0
 
+        if args.group_by_date:
+            for group in group_by_date(messages, lambda m: m.get('date')):
+                print(f"--- {group['date'] or 'Unknown date'} ---")
+                for m in group['items']:
+                    sender = "Me" if m.get('is_from_me') else contact.name
+                    text = m.get('text', '[media]') or '[media]'
+                    time_str = m['date'][11:16] if m.get('date') else "--:--"
+                    print(f"{time_str} {format_sender(args, sender)}: {shorten(args, text, 200)}")
+                    _print_reactions_inline(m, contact.name)
+            return 0
+
         for m in messages:
             sender = "Me" if m.get('is_from_me') else contact.name
             text = m.get('text', '[media]') or '[media]'
-            print(f"{sender}: {text[:200]}")
+            print(f"{format_sender(args, sender)}: {shorten(args, text, 200)}")
+            _print_reactions_inline(m, contact.name)
+
+    return 0
+
+
+def _print_messages_page(mi: MessagesInterface, contact, args):
+    """Print one page of messages and the tokens for the adjacent pages."""
+    try:
+        token = pagination_token_decode(args.page_token) if args.page_token else None
+        page = mi.messages_for_phone_paged(contact.phone, limit=args.limit, token=token)
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        return 1
+
+    if args.json:
+        print(json.dumps(page, indent=2, default=str))
+    else:
+        if not page['messages']:
+            print("No messages found.")
+            return 0
+
+        for m in page['messages']:
+            sender = "Me" if m.get('is_from_me') else contact.name
+            text = m.get('text', '[media]') or '[media]'
+            print(f"{format_sender(args, sender)}: {shorten(args, text, 200)}")
+        if page['next_token'] or page['prev_token']:
+            print()
+        if page['next_token']:
+            print(f"Older: --page-token {page['next_token']}")
+        if page['prev_token']:
+            print(f"Newer: --page-token {page['prev_token']}")
 
     return 0
 
 
+def _print_reactions_inline(message: dict, contact_name: str):
+    """Print a message's tapbacks indented under it, e.g. "  ❤️ Alice | 👍 Me"."""
+    reactions = message.get('reactions')
+    if not reactions:
+        return
+    print("  " + " | ".join(
+        f"{r['emoji']} {'Me' if r['is_from_me'] else contact_name}" for r in reactions
+    ))
+
+
 def cmd_recent(args):
     """Get recent conversations across all contacts."""
     mi, _ = get_interfaces()
 
+    if args.handle_pattern:
+        return _print_matching_conversations(mi, args)
+
     conversations = mi.get_all_recent_conversations(limit=args.limit)
 
     if args.json:
@@ -136,14 +624,35 @@ def cmd_recent(args):
         print("Recent Conversations:")
         print("-" * 60)
         for conv in conversations:
-            handle = conv.get('handle_id', 'Unknown')
-            last_msg = conv.get('last_message', '')[:80]
-            timestamp = conv.get('last_message_date', '')
+            handle = conv.get('contact_name') or conv.get('phone') or 'Unknown'
+            last_msg = shorten(args, conv.get('text') or '', 80)
+            timestamp = conv.get('date') or ''
             print(f"{handle}: {last_msg} ({timestamp})")
 
     return 0
 
 
+def _print_matching_conversations(mi: MessagesInterface, args):
+    """Print conversations whose handle matches --handle-pattern."""
+    conversations = mi.conversations_matching(args.handle_pattern, limit=args.limit)
+
+    if args.json:
+        print(json.dumps(conversations, indent=2, default=str))
+    else:
+        if not conversations:
+            print(f"No conversations with handles matching '{args.handle_pattern}'.")
+            return 0
+
+        print(f"Conversations matching '{args.handle_pattern}':")
+        print("-" * 60)
+        for conv in conversations:
+            last_msg = shorten(args, conv.get('last_message') or '', 80)
+            print(f"{conv['phone']} ({conv['message_count']} messages): {last_msg} "
+                  f"({conv.get('last_message_date') or ''})")
+
+    return 0
+
+
 def cmd_unread(args):
     """Get unread messages."""
     mi, _ = get_interfaces()
@@ -160,13 +669,56 @@ def cmd_unread(args):
         print(f"Unread Messages ({len(messages)}):")
         print("-" * 60)
         for m in messages:
-            sender = m.get('sender', 'Unknown')
+            sender = m.get('phone', 'Unknown')
             text = m.get('text', '[media]') or '[media]'
-            print(f"{sender}: {text[:150]}")
+            print(f"{format_sender(args, sender)}: {shorten(args, text, 150)}")
 
     return 0
 
 
+def cmd_watch(args):
+    """Print new messages as they arrive, until interrupted."""
+    mi, cm = get_interfaces()
+
+    phone = None
+    if args.contact:
+        contact = resolve_contact(cm, args.contact)
+        if not contact:
+            print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+            return 1
+        phone = contact.phone
+
+    def reload_contacts(signum, frame):
+        # kill -HUP picks up contacts.json edits without restarting
+        try:
+            cm.reload()
+            print(f"Reloaded {len(cm.contacts)} contacts.", file=sys.stderr)
+        except (ValueError, FileNotFoundError) as e:
+            print(f"Could not reload contacts: {e}", file=sys.stderr)
+
+    if hasattr(signal, 'SIGHUP'):
+        signal.signal(signal.SIGHUP, reload_contacts)
+
+    incoming = mi.watch(phone, interval=args.interval)
+    print("Watching for new messages (Ctrl-C to stop, kill -HUP to reload contacts)...", file=sys.stderr)
+
+    try:
+        while True:
+            m = incoming.get()
+            if args.json:
+                print(json.dumps(m, default=str), flush=True)
+            else:
+                if m.get('is_from_me'):
+                    sender = "Me"
+                else:
+                    contact = cm.get_contact_by_phone(m['phone'])
+                    sender = contact.name if contact else m['phone']
+                print(f"[{(m.get('date') or '')[:19]}] {format_sender(args, sender)}: "
+                      f"{shorten(args, m.get('text') or '', 200)}", flush=True)
+    except KeyboardInterrupt:
+        return 0
+
+
 def cmd_send(args):
     """Send a message to a contact."""
     mi, cm = get_interfaces()
@@ -178,17 +730,50 @@ def cmd_send(args):
 
     message = " ".join(args.message)
 
-    print(f"Sending to {contact.name} ({contact.phone}): {message[:50]}...", file=sys.stderr)
-    result = mi.send_message(contact.phone, message)
+    print(f"Sending to {contact.name} ({contact.phone}): {summarize_text(message, 50)}", file=sys.stderr)
+    if args.confirm:
+        return _send_confirmed(mi, contact, message, args)
+    if args.dismiss_followup:
+        result = mi.send_message_with_followup_dismiss(contact.phone, message,
+                                                       SnoozeStore(str(SNOOZES_CONFIG)))
+    else:
+        result = mi.send_message(contact.phone, message)
 
     if result.get('success'):
         print("Message sent successfully.", file=sys.stderr)
+        if result.get('snoozed_until'):
+            print(f"Follow-ups for {contact.name} snoozed until {result['snoozed_until']}.",
+                  file=sys.stderr)
+        if args.notify_on_send:
+            notify("iMessage Gateway", f"Sent to {contact.name}")
         return 0
     else:
         print(f"Failed to send: {result.get('error', 'Unknown error')}", file=sys.stderr)
         return 1
 
 
+def _send_confirmed(mi: MessagesInterface, contact, message: str, args):
+    """Send via send_and_confirm and report whether delivery was confirmed."""
+    result = mi.send_and_confirm(contact.phone, message, timeout_secs=args.timeout)
+
+    if not result['sent']:
+        print(f"Failed to send: {result.get('error') or 'Unknown error'}", file=sys.stderr)
+        return 1
+
+    if result['delivered']:
+        print(f"Message delivered ({result['elapsed_secs']}s).", file=sys.stderr)
+    else:
+        print(f"Message sent, but delivery not confirmed within {args.timeout}s.", file=sys.stderr)
+
+    if args.dismiss_followup:
+        until = SnoozeStore(str(SNOOZES_CONFIG)).snooze(contact.phone)
+        print(f"Follow-ups for {contact.name} snoozed until {until}.", file=sys.stderr)
+    if args.notify_on_send:
+        notify("iMessage Gateway", f"Sent to {contact.name}")
+
+    return 0 if result['delivered'] else 2
+
+
 def cmd_send_by_phone(args):
     """Send a message directly to a phone number (no contact lookup)."""
     mi, _ = get_interfaces()
@@ -199,10 +784,12 @@ def cmd_send_by_phone(args):
 
     message = " ".join(args.message)
 
-    print(f"Sending to {phone}: {message[:50]}...", file=sys.stderr)
+    print(f"Sending to {phone}: {summarize_text(message, 50)}", file=sys.stderr)
     result = mi.send_message(phone, message)
 
     if result.get('success'):
+        if args.notify_on_send:
+            notify("iMessage Gateway", f"Sent to {phone}")
         if args.json:
             print(json.dumps({"success": True, "phone": phone, "message": message}))
         else:
@@ -217,41 +804,544 @@ def cmd_send_by_phone(args):
         return 1
 
 
-def cmd_contacts(args):
-    """List all contacts."""
-    _, cm = get_interfaces()
+def cmd_contacts(args):
+    """List all contacts."""
+    mi, cm = get_interfaces()
+
+    if args.relationship_types:
+        types = cm.unique_relationship_types()
+        if args.json:
+            print(json.dumps(types, indent=2))
+        else:
+            print(f"Relationship types ({len(types)}):")
+            print("-" * 40)
+            for t in types:
+                print(f"{t}: {len(cm.contacts_by_type(t))} contacts")
+        return 0
+
+    if args.fuzzy:
+        ranked = cm.fuzzy_search_ranked(args.fuzzy, limit=args.limit)
+        if args.json:
+            print(json.dumps([dict(c.to_dict(), score=score) for score, c in ranked], indent=2))
+        else:
+            print(f"Contacts matching \"{args.fuzzy}\" ({len(ranked)}):")
+            print("-" * 40)
+            for score, c in ranked:
+                print(f"{score:.2f}  {c.name}: {c.phone}")
+        return 0
+
+    contacts = cm.contacts
+    title = "Contacts"
+    if args.contact:
+        contact = resolve_contact(cm, args.contact)
+        if not contact:
+            print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+            return 1
+        contacts = [contact]
+    criteria = SearchCriteria(
+        name_contains=args.name_contains,
+        phone_prefix=args.phone_prefix,
+        relationship_type=args.type
+    )
+    if args.name_contains or args.phone_prefix or args.type:
+        contacts = cm.search(criteria)
+        title = "Matching contacts"
+    if args.added_since:
+        selected = {id(c) for c in contacts}
+        contacts = [c for c in cm.contacts_added_recently(args.added_since) if id(c) in selected]
+        title += f" added in the last {args.added_since} days"
+    if args.without_notes:
+        without = {id(c) for c in cm.contacts_without_notes()}
+        contacts = [c for c in contacts if id(c) in without]
+        title += " without notes"
+    if args.has_email:
+        with_email = {id(c) for c in cm.contacts_with_email()}
+        contacts = [c for c in contacts if id(c) in with_email]
+        title += " with email"
+
+    if args.export_to_macos:
+        try:
+            added = cm.export_to_macos(contacts)
+        except RuntimeError as e:
+            print(f"Failed to export contacts: {e}", file=sys.stderr)
+            return 1
+        if args.json:
+            print(json.dumps({"exported": len(contacts), "added": added}, indent=2))
+        else:
+            print(f"Added {added} of {len(contacts)} contacts to Contacts.app "
+                  f"({len(contacts) - added} already present).")
+        return 0
+
+    last_contacted = {}
+    if args.sort_by == 'last-contacted':
+        selected = {id(c) for c in contacts}
+        ordered = [(c, d) for c, d in cm.sorted_by_last_contact(mi) if id(c) in selected]
+        contacts = [c for c, _ in ordered]
+        last_contacted = {id(c): d for c, d in ordered}
+
+    first_seen = {}
+    if args.first_seen:
+        first_seen = {id(c): mi.handle_first_seen(c.phone) for c in contacts}
+
+    handles = {}
+    if args.handles:
+        handles = {id(c): mi.all_handles_for_phone(c.phone) for c in contacts}
+
+    metadata = {}
+    if args.show_metadata:
+        metadata = {c.phone: mi.handle_metadata(c.phone) for c in contacts}
+
+    if args.json:
+        results = []
+        for c in contacts:
+            entry = c.to_dict()
+            if args.show_metadata:
+                entry["handle_metadata"] = metadata.get(c.phone)
+            if args.sort_by == 'last-contacted':
+                entry["last_contacted"] = last_contacted[id(c)]
+            if args.first_seen:
+                entry["first_seen"] = first_seen[id(c)]
+            if args.handles:
+                entry["handles"] = handles[id(c)]
+            results.append(entry)
+        print(json.dumps(results, indent=2))
+    else:
+        total = f" of {cm.count()}" if len(contacts) != cm.count() else ""
+        print(f"{title} ({len(contacts)}{total}):")
+        print("-" * 40)
+        for c in contacts:
+            added = f" (added {c.added_at[:10]})" if args.added_since and c.added_at else ""
+            info = ""
+            if args.show_metadata:
+                meta = metadata.get(c.phone)
+                if meta:
+                    country = f", {meta['country']}" if meta.get('country') else ""
+                    info = f" [{meta.get('service') or 'unknown'}{country}]"
+                else:
+                    info = " [no handle]"
+            if args.sort_by == 'last-contacted':
+                last = last_contacted[id(c)]
+                info += f" - last contacted {last[:10] if last else 'never'}"
+            if args.first_seen:
+                first = first_seen[id(c)]
+                info += f" - first seen {first[:10] if first else 'never'}"
+            if args.handles:
+                info += f" - handles: {', '.join(handles[id(c)]) or 'none'}"
+            email = f" <{c.email}>" if args.has_email else ""
+            print(f"{c.name}: {c.phone}{email}{added}{info}")
+        if args.without_notes and contacts:
+            print('\nAdd context with: add-contact NAME PHONE --notes "..."')
+
+    return 0
+
+
+def cmd_analytics(args):
+    """Get conversation analytics for a contact."""
+    mi, cm = get_interfaces()
+
+    if args.chat_guid:
+        return _print_group_analytics(mi, args)
+
+    if args.all_contacts:
+        return _print_all_contacts_analytics(mi, cm, args)
+
+    if args.pair:
+        return _print_pair_interaction(mi, cm, args)
+
+    if args.stats_timeline:
+        return _print_stats_timeline(mi, cm, args)
+
+    if args.sentiment_trend:
+        return _print_sentiment_trend(mi, cm, args)
+
+    if args.heatmap:
+        return _print_activity_heatmap(mi, cm, args)
+
+    if args.topics:
+        return _print_top_topics(mi, cm, args)
+
+    if args.duplicates:
+        return _print_duplicate_messages(mi, cm, args)
+
+    if args.full:
+        return _print_full_analytics(mi, cm, args)
+
+    if args.contact:
+        contact = resolve_contact(cm, args.contact)
+        if not contact:
+            print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+            return 1
+        analytics = mi.get_conversation_analytics(contact.phone, days=args.days,
+                                                  by_service=args.by_service)
+        if args.edited_count:
+            analytics['edited_count'] = mi.edited_message_count(contact.phone)
+        if args.longest_gap:
+            analytics['longest_gap'] = mi.longest_gap_ever(contact.phone)
+        if args.percentiles:
+            analytics['response_times'] = mi.response_time_analytics(
+                contact.phone, days=args.days, percentiles=True
+            )
+        if args.emoji_sentiment:
+            analytics['emoji_sentiment'] = mi.emoji_sentiment_ratio(contact.phone, days=args.days)
+    elif args.edited_count or args.longest_gap or args.percentiles or args.emoji_sentiment:
+        print("Error: --edited-count, --longest-gap, --percentiles and --emoji-sentiment "
+              "require a contact", file=sys.stderr)
+        return 1
+    else:
+        analytics = mi.get_conversation_analytics(days=args.days, by_service=args.by_service)
+
+    if args.json:
+        print_json(args, analytics)
+    else:
+        print("Conversation Analytics:")
+        print("-" * 40)
+        top_contacts = analytics.get('top_contacts') if not args.no_chart else None
+        for key, value in analytics.items():
+            if key == 'top_contacts' and top_contacts:
+                continue
+            if key == 'longest_conversation_day' and value:
+                print(f"Busiest date: {value['date']} ({value['message_count']} messages)")
+                continue
+            if key == 'longest_gap' and value:
+                value = f"{value['duration_hours']}h ({value['start'][:16]} to {value['end'][:16]})"
+            if key == 'emoji_sentiment' and value:
+                ratio = value['positive_ratio']
+                value = (f"{value['positive_messages']} positive, {value['negative_messages']} negative, "
+                         f"{value['neutral_messages']} neutral"
+                         + (f" ({ratio:.0%} positive)" if ratio is not None else ""))
+            if key == 'response_times' and value:
+                print("response_times:")
+                for side in ('my_responses', 'their_responses'):
+                    stats = value.get(side, {})
+                    parts = [f"{k.replace('_seconds', '')}={v}s" for k, v in stats.items()
+                             if k != 'count' and v is not None]
+                    print(f"  {side} ({stats.get('count', 0)}): {', '.join(parts) or 'N/A'}")
+                continue
+            print(f"{key}: {value}")
+
+        if top_contacts:
+            data = []
+            for t in top_contacts:
+                contact = cm.get_contact_by_phone(t['phone'])
+                data.append((contact.name if contact else t['phone'], t['message_count']))
+            print("\nTop contacts:")
+            print(bar_chart(data))
+
+    return 0
+
+
+def _print_all_contacts_analytics(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print message counts for every contact, busiest first."""
+    stats = mi.batch_analytics([c.phone for c in cm.contacts], days=args.days)
+
+    rows = []
+    for c in cm.contacts:
+        if c.phone in stats:
+            rows.append({"name": c.name, "phone": c.phone, **stats[c.phone]})
+    rows.sort(key=lambda r: r['total_messages'], reverse=True)
+
+    if args.json:
+        print_json(args, rows)
+    else:
+        if not rows:
+            print("No messages with contacts in this period.")
+            return 0
+
+        print(f"Contact Analytics (last {args.days} days):")
+        print("-" * 60)
+        for r in rows:
+            print(f"{r['name']}: {r['total_messages']} messages "
+                  f"({r['sent_count']} sent, {r['received_count']} received)")
+
+    return 0
+
+
+def _print_top_topics(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print keyword clusters for a conversation."""
+    contact = resolve_contact(cm, args.contact) if args.contact else None
+    if not contact:
+        print(f"Contact '{args.contact}' not found." if args.contact else
+              "Error: --topics requires a contact", file=sys.stderr)
+        return 1
+
+    topics = mi.top_topics(contact.phone, days=args.days, n_topics=args.topics)
+
+    if args.json:
+        print_json(args, topics)
+    else:
+        print(f"Top topics with {contact.name} (last {args.days} days):")
+        print("-" * 60)
+        if not topics:
+            print("Not enough messages to find topics.")
+        for i, topic in enumerate(topics, 1):
+            print(f"{i}. {', '.join(topic['keywords'])} ({topic['message_count']} messages)")
+
+    return 0
+
+
+def _print_duplicate_messages(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print messages that were sent twice within a short window."""
+    contact = resolve_contact(cm, args.contact) if args.contact else None
+    if not contact:
+        print(f"Contact '{args.contact}' not found." if args.contact else
+              "Error: --duplicates requires a contact", file=sys.stderr)
+        return 1
+
+    pairs = mi.duplicate_messages(contact.phone, time_window_secs=args.duplicate_window)
+
+    if args.json:
+        print_json(args, pairs)
+    else:
+        if not pairs:
+            print(f"No duplicate messages with {contact.name}.")
+            return 0
+
+        print(f"Duplicate messages with {contact.name} (within {args.duplicate_window}s):")
+        print("-" * 60)
+        for pair in pairs:
+            original, duplicate = pair['original'], pair['duplicate']
+            sender = "Me" if original['is_from_me'] else contact.name
+            print(f"[{original['date'][:19]}] {sender}: {shorten(args, original['text'], 100)}")
+            print(f"    repeated {pair['seconds_apart']}s later ({duplicate['guid']})")
+
+    return 0
+
+
+def _print_activity_heatmap(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print a day-of-week by hour grid of message counts."""
+    contact = None
+    if args.contact:
+        contact = resolve_contact(cm, args.contact)
+        if not contact:
+            print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+            return 1
+
+    grid = mi.activity_heatmap(contact.phone if contact else None, days=args.days)
+
+    if args.json:
+        print_json(args, grid)
+    else:
+        scope = f"with {contact.name}" if contact else "across all conversations"
+        print(f"Activity {scope} (last {args.days} days, local time):")
+        print("-" * 60)
+        print(heatmap_chart(grid, ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]))
+
+    return 0
+
+
+def _print_sentiment_trend(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print word and emoji sentiment per period."""
+    contact = resolve_contact(cm, args.contact) if args.contact else None
+    if not contact:
+        print(f"Contact '{args.contact}' not found." if args.contact else
+              "Error: --sentiment-trend requires a contact", file=sys.stderr)
+        return 1
+
+    trend = mi.sentiment_trend(contact.phone, period=args.period, periods=args.periods)
+
+    if args.json:
+        print_json(args, trend)
+    else:
+        def pct(ratio):
+            return f"{ratio:.0%}" if ratio is not None else "-"
+
+        print(f"Sentiment trend with {contact.name} ({args.period}):")
+        print("-" * 60)
+        print(f"{'Period':<12} {'Messages':>9} {'Words':>7} {'Emoji':>7}")
+        for p in trend:
+            print(f"{p['label']:<12} {p['message_count']:>9} "
+                  f"{pct(p['positive_ratio']):>7} {pct(p['emoji_sentiment_ratio']):>7}")
+
+    return 0
+
+
+def _print_stats_timeline(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print sent/received counts and reply speed per period."""
+    contact = resolve_contact(cm, args.contact) if args.contact else None
+    if not contact:
+        print(f"Contact '{args.contact}' not found." if args.contact else
+              "Error: --timeline requires a contact", file=sys.stderr)
+        return 1
+
+    if args.bucket_days:
+        # Rolling windows ending now, reshaped to match the calendar timeline
+        buckets = mi.handle_stats_timeline(contact.phone, bucket_days=args.bucket_days,
+                                           buckets=args.periods)
+        if args.json:
+            print_json(args, buckets)
+            return 0
+        timeline = [
+            {"label": b['start_date'][:10], "sent": b['sent'], "received": b['received'],
+             "response_time_median": b['response_time_p50']}
+            for b in reversed(buckets)
+        ]
+        period = f"{args.bucket_days}-day windows"
+    else:
+        timeline = mi.stats_timeline(contact.phone, period=args.period, periods=args.periods)
+        period = args.period
+
+    if args.json:
+        print_json(args, timeline)
+    else:
+        print(f"Conversation timeline with {contact.name} ({period}):")
+        print("-" * 60)
+        print(f"{'Period':<12} {'Sent':>6} {'Received':>9}  Median reply")
+        for p in timeline:
+            median = p['response_time_median']
+            reply = f"{median / 60:.0f} min" if median is not None else "-"
+            print(f"{p['label']:<12} {p['sent']:>6} {p['received']:>9}  {reply}")
+        if not args.no_chart:
+            print()
+            print(bar_chart([(p['label'], p['sent'] + p['received']) for p in timeline]))
+
+    return 0
+
+
+def _print_full_analytics(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print every analytics view for one contact."""
+    contact = resolve_contact(cm, args.contact) if args.contact else None
+    if not contact:
+        print(f"Contact '{args.contact}' not found." if args.contact else
+              "Error: --full requires a contact", file=sys.stderr)
+        return 1
+
+    bundle = mi.full_analytics_bundle(contact.phone, cm, days=args.days)
+
+    if args.json:
+        print_json(args, bundle)
+        return 0
+
+    basic = bundle['basic']
+    streak = bundle['streak']
+    health = bundle['health']
+    mine = bundle['response_times'].get('my_responses', {})
+    theirs = bundle['response_times'].get('their_responses', {})
+
+    def minutes(seconds):
+        return f"{seconds / 60:.0f} min" if seconds is not None else "N/A"
+
+    print(f"Full Analytics: {contact.name} (last {args.days} days)")
+    print("-" * 60)
+    print(f"Messages: {basic.get('total_messages', 0)} "
+          f"({basic.get('sent_count', 0)} sent, {basic.get('received_count', 0)} received)")
+    print(f"Median reply: you {minutes(mine.get('median_seconds'))}, "
+          f"them {minutes(theirs.get('median_seconds'))}")
+    print(f"Longest streak: {streak['longest_days']} days"
+          + (f" from {streak['longest_start']}" if streak['longest_start'] else ""))
+    print(f"Current streak: {streak['current_days']} days")
+    if health.get('last_message'):
+        print(f"Last message: {health['last_message'][:10]} ({health['days_since_last_message']} days ago)")
+
+    print("\nMessages per month:")
+    print(bar_chart([(m['label'], m['sent'] + m['received']) for m in bundle['per_month']]))
+    print("\nMessages by hour:")
+    print(bar_chart([(f"{h['hour']:02d}", h['count']) for h in bundle['per_hour']]))
+
+    return 0
+
+
+def _print_pair_interaction(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print how often two contacts mention each other."""
+    contacts = []
+    for name in args.pair:
+        contact = resolve_contact(cm, name)
+        if not contact:
+            print(f"Contact '{name}' not found.", file=sys.stderr)
+            return 1
+        contacts.append(contact)
+
+    pair = mi.contact_pair_interaction(contacts[0], contacts[1])
+
+    if args.json:
+        print_json(args, pair)
+    else:
+        print(f"Mentions between {pair['contact_a']} and {pair['contact_b']}:")
+        print("-" * 40)
+        print(f"{pair['contact_a']} mentioned {pair['contact_b']}: {pair['a_mentions_b']}")
+        print(f"{pair['contact_b']} mentioned {pair['contact_a']}: {pair['b_mentions_a']}")
+        print(f"Mutual: {'yes (' + pair['mutual_contact_name'] + ')' if pair['is_mutual'] else 'no'}")
+
+    return 0
+
+
+def _print_group_analytics(mi: MessagesInterface, args):
+    """Print per-participant analytics for a group chat."""
+    analytics = mi.group_chat_analytics(args.chat_guid, days=args.days)
+
+    if analytics.get('error'):
+        print(f"Error: {analytics['error']}", file=sys.stderr)
+        return 1
 
     if args.json:
-        print(json.dumps([c.to_dict() for c in cm.contacts], indent=2))
+        print_json(args, analytics)
     else:
-        print(f"Contacts ({len(cm.contacts)}):")
+        name = analytics.get('display_name') or analytics.get('chat_guid')
+        print(f"Group Analytics: {name}")
         print("-" * 40)
-        for c in cm.contacts:
-            print(f"{c.name}: {c.phone}")
+        print(f"Total messages: {analytics.get('total_messages', 0)}")
+        print(f"First message: {analytics.get('first_message') or 'N/A'}")
+        print(f"Last message: {analytics.get('last_message') or 'N/A'}")
+        print("\nMessages by participant:")
+        participants = analytics.get('messages_by_handle', [])
+        if args.no_chart:
+            for p in participants:
+                print(f"  {p['handle']}: {p['message_count']}")
+        elif participants:
+            print(bar_chart([(p['handle'], p['message_count']) for p in participants]))
 
     return 0
 
 
-def cmd_analytics(args):
-    """Get conversation analytics for a contact."""
+def cmd_compare(args):
+    """Compare communication patterns with two contacts."""
     mi, cm = get_interfaces()
 
-    if args.contact:
-        contact = resolve_contact(cm, args.contact)
+    contacts = []
+    for name in (args.contact_a, args.contact_b):
+        contact = resolve_contact(cm, name)
         if not contact:
-            print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+            print(f"Contact '{name}' not found.", file=sys.stderr)
             return 1
-        analytics = mi.get_conversation_analytics(contact.phone, days=args.days)
-    else:
-        analytics = mi.get_conversation_analytics(days=args.days)
+        contacts.append(contact)
+    contact_a, contact_b = contacts
+
+    comparison = mi.compare_conversations(contact_a.phone, contact_b.phone, days=args.days)
 
     if args.json:
-        print(json.dumps(analytics, indent=2, default=str))
-    else:
-        print("Conversation Analytics:")
-        print("-" * 40)
-        for key, value in analytics.items():
-            print(f"{key}: {value}")
+        print(json.dumps(comparison, indent=2, default=str))
+        return 0
+
+    names = {contact_a.phone: contact_a.name, contact_b.phone: contact_b.name}
+
+    def minutes(seconds):
+        return f"{seconds / 60:.0f} min" if seconds is not None else "N/A"
+
+    def row(label, value_a, value_b):
+        print(f"{label:<22} {str(value_a):>18} {str(value_b):>18}")
+
+    a, b = comparison['a'], comparison['b']
+    a_times, b_times = comparison['a_response_times'], comparison['b_response_times']
+
+    print(f"Comparison (last {args.days} days):")
+    print("-" * 60)
+    row("", contact_a.name[:18], contact_b.name[:18])
+    row("Messages", a.get('total_messages', 0), b.get('total_messages', 0))
+    row("Sent", a.get('sent_count', 0), b.get('sent_count', 0))
+    row("Received", a.get('received_count', 0), b.get('received_count', 0))
+    row("Avg per day", a.get('avg_daily_messages', 0), b.get('avg_daily_messages', 0))
+    row("Your median reply",
+        minutes(a_times.get('my_responses', {}).get('median_seconds')),
+        minutes(b_times.get('my_responses', {}).get('median_seconds')))
+    row("Their median reply",
+        minutes(a_times.get('their_responses', {}).get('median_seconds')),
+        minutes(b_times.get('their_responses', {}).get('median_seconds')))
+    row("Last message",
+        (comparison['a_last_message'] or 'never')[:10],
+        (comparison['b_last_message'] or 'never')[:10])
+    print("-" * 60)
+    print(f"More messages: {names.get(comparison['winner_by_volume'], comparison['winner_by_volume'])}")
+    recency = comparison['winner_by_recency']
+    print(f"More recent: {names.get(recency, recency) if recency else 'N/A'}")
 
     return 0
 
@@ -260,7 +1350,27 @@ def cmd_followup(args):
     """Detect messages needing follow-up."""
     mi, cm = get_interfaces()
 
-    followups = mi.detect_follow_up_needed(days=args.days, min_stale_days=args.stale)
+    if args.unknown_only:
+        followups = mi.unknown_handle_followups(cm, days=args.days, min_stale_days=args.stale,
+                                                include_context=args.context)
+    else:
+        followups = mi.detect_follow_up_needed(days=args.days, min_stale_days=args.stale,
+                                               include_context=args.context)
+
+    # Hide handles dismissed via send --dismiss-followup
+    snoozes = SnoozeStore(str(SNOOZES_CONFIG))
+    if snoozes.snoozes:
+        summary = followups.get("summary", {})
+        for key, items in followups.items():
+            if isinstance(items, list):
+                followups[key] = [i for i in items if not snoozes.is_snoozed(i.get('phone') or '')]
+                if key in summary:
+                    summary[key] = len(followups[key])
+        if "total_action_items" in summary:
+            summary["total_action_items"] = sum(
+                summary.get(k, 0) for k in
+                ("unanswered_questions", "pending_promises", "waiting_on_them", "stale_conversations")
+            )
 
     if args.json:
         print(json.dumps(followups, indent=2, default=str))
@@ -292,11 +1402,50 @@ def cmd_followup(args):
             print(f"\n--- {category.replace('_', ' ').title()} ---")
             for item in items:
                 phone = item.get('phone')
-                contact = cm.get_contact_by_phone(phone) if phone else None
+                contact = cm.get_contact_by_handle(phone) if phone else None
                 name = contact.name if contact else phone or "Unknown"
                 text = item.get('text') or item.get('last_message', '')
                 date = item.get('date', '')
-                print(f"  {name}: {text[:100]} ({date})")
+                label = f"{format_priority(item['priority_label'])} " if item.get('priority_label') else ""
+                print(f"  {label}{name}: {shorten(args, text, 120, sentences=True)} ({date})")
+                for ctx in item.get('context_messages', [])[-2:]:
+                    sender = "Me" if ctx.get('is_from_me') else name
+                    print(f"      {sender}: {shorten(args, ctx.get('text', ''), 120, sentences=True)}")
+
+    return 0
+
+
+def cmd_sent(args):
+    """Show messages you sent to a contact."""
+    mi, cm = get_interfaces()
+
+    contact = resolve_contact(cm, args.contact)
+    if not contact:
+        print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+        return 1
+
+    if args.unacknowledged:
+        messages = mi.unacknowledged_outbound(contact.phone, days=args.days,
+                                              min_wait_hours=args.wait_hours)
+        title = f"Unacknowledged messages to {contact.name} (no reply within {args.wait_hours}h)"
+    else:
+        messages = [m for m in mi.get_messages_by_phone(contact.phone, limit=args.limit)
+                    if m.get('is_from_me')]
+        title = f"Messages sent to {contact.name}"
+
+    if args.json:
+        print(json.dumps(messages, indent=2, default=str))
+    else:
+        if not messages:
+            print("No unacknowledged messages." if args.unacknowledged else "No sent messages.")
+            return 0
+
+        print(f"{title}:")
+        print("-" * 60)
+        for m in messages:
+            date = (m.get('date') or '')[:16]
+            waiting = f" ({m['hours_waiting']}h ago)" if 'hours_waiting' in m else ""
+            print(f"{date}{waiting}: {shorten(args, m.get('text', ''), 100)}")
 
     return 0
 
@@ -345,8 +1494,23 @@ def cmd_group_messages(args):
         limit=args.limit
     )
 
+    breakdown = None
+    if args.breakdown:
+        if not args.group_id:
+            print("Error: --breakdown requires --group-id", file=sys.stderr)
+            return 1
+        breakdown = mi.group_message_breakdown(args.group_id, days=args.days)
+
+    if args.reply_counts:
+        counts = mi.thread_reply_counts([m.get('guid') for m in messages])
+        for m in messages:
+            m['reply_count'] = counts.get(m.get('guid'), 0)
+
     if args.json:
-        print(json.dumps(messages, indent=2, default=str))
+        if breakdown is not None:
+            print(json.dumps({"messages": messages, "breakdown": breakdown}, indent=2, default=str))
+        else:
+            print(json.dumps(messages, indent=2, default=str))
     else:
         if not messages:
             print("No group messages found.")
@@ -358,7 +1522,16 @@ def cmd_group_messages(args):
             sender = "Me" if m.get('is_from_me') else m.get('sender_handle', 'Unknown')
             text = m.get('text', '[media]') or '[media]'
             date = m.get('date', '')
-            print(f"[{date}] {sender}: {text[:150]}")
+            replies = ""
+            if args.reply_counts and m['reply_count']:
+                replies = f" ({m['reply_count']} {'reply' if m['reply_count'] == 1 else 'replies'})"
+            print(f"[{date}] {format_sender(args, sender)}: {shorten(args, text, 150)}{replies}")
+
+        if breakdown is not None:
+            period = f" (last {args.days} days)" if args.days else ""
+            print(f"\nMessages by participant{period}:")
+            for handle, count in breakdown.items():
+                print(f"  {handle}: {count}")
 
     return 0
 
@@ -375,6 +1548,56 @@ def cmd_attachments(args):
             return 1
         phone = contact.phone
 
+    if args.total_size:
+        total = mi.attachment_total_size(phone=phone)
+        if args.json:
+            print(json.dumps({"phone": phone, "total_bytes": total}, indent=2))
+        else:
+            scope = f"with {contact.name}" if phone else "across all conversations"
+            print(f"Attachments {scope}: {format_bytes(total)}")
+        return 0
+
+    if args.data_usage:
+        if not phone:
+            print("Error: --data-usage requires a contact", file=sys.stderr)
+            return 1
+        usage = mi.attachment_data_usage(phone)
+        if args.json:
+            print(json.dumps({"phone": phone, **usage}, indent=2))
+        else:
+            print(f"Attachment data with {contact.name}: {format_bytes(usage['total_bytes'])}")
+            print("-" * 40)
+            print(f"Sent: {format_bytes(usage['sent_bytes'])}")
+            print(f"Received: {format_bytes(usage['received_bytes'])}")
+            for t in usage['by_type']:
+                print(f"  {t['type']}: {format_bytes(t['total_bytes'])} ({t['count']} files)")
+        return 0
+
+    if args.audio_summary:
+        if not phone:
+            print("Error: --audio-summary requires a contact", file=sys.stderr)
+            return 1
+        audio = mi.audio_message_count(phone)
+        if args.json:
+            print(json.dumps({"phone": phone, **audio}, indent=2))
+        else:
+            print(f"Voice memos with {contact.name}: {audio['sent']} sent, {audio['received']} received")
+        return 0
+
+    if args.list_types:
+        mime_types = mi.unique_mime_types(phone=phone)
+        if args.json:
+            print(json.dumps(mime_types, indent=2))
+        else:
+            if not mime_types:
+                print("No attachment types found.")
+                return 0
+            print(f"Attachment types ({len(mime_types)}):")
+            print("-" * 40)
+            for mime in mime_types:
+                print(mime)
+        return 0
+
     attachments = mi.get_attachments(
         phone=phone,
         mime_type_filter=args.type,
@@ -394,7 +1617,7 @@ def cmd_attachments(args):
             filename = a.get('filename') or a.get('transfer_name', 'Unknown')
             mime = a.get('mime_type', 'unknown')
             size = a.get('total_bytes', 0)
-            size_str = f"{size / 1024:.1f}KB" if size else "N/A"
+            size_str = format_bytes(size) if size else "N/A"
             date = a.get('message_date', '')
             print(f"{filename} ({mime}, {size_str}) - {date}")
 
@@ -404,6 +1627,8 @@ def cmd_attachments(args):
 def cmd_add_contact(args):
     """Add a new contact."""
     _, cm = get_interfaces()
+    if not contacts_writable(cm):
+        return 1
 
     try:
         cm.add_contact(
@@ -419,15 +1644,170 @@ def cmd_add_contact(args):
         return 1
 
 
+def cmd_edit_contact(args):
+    """Edit an existing contact."""
+    if args.name and args.name_flag and args.name != args.name_flag:
+        print("Error: give the contact name once, either positionally or with --name", file=sys.stderr)
+        return 1
+    name = args.name_flag or args.name
+    if not name:
+        print("Error: --name is required", file=sys.stderr)
+        return 1
+
+    _, cm = get_interfaces()
+    if not contacts_writable(cm):
+        return 1
+
+    update = ContactUpdate(
+        new_name=args.new_name,
+        new_phone=args.phone,
+        new_relationship_type=args.relationship,
+        new_notes=args.notes,
+        add_alias=args.add_alias
+    )
+    if update == ContactUpdate():
+        print("Nothing to change. Pass --new-name, --phone, --relationship-type, --notes or --add-alias.",
+              file=sys.stderr)
+        return 1
+
+    if not cm.update_contact(name, update):
+        print(f"Contact '{name}' not found.", file=sys.stderr)
+        return 1
+
+    print(f"Contact '{name}' updated successfully.")
+    return 0
+
+
+def cmd_import_contacts(args):
+    """Import contacts from an external source."""
+    _, cm = get_interfaces()
+    if not contacts_writable(cm):
+        return 1
+
+    try:
+        if args.format == 'google-csv':
+            if not args.file:
+                print("Error: --format google-csv requires --file", file=sys.stderr)
+                return 1
+            imported = cm.import_from_google_contacts_csv(args.file)
+        else:
+            imported = cm.import_from_applescript()
+    except (RuntimeError, OSError, ValueError) as e:
+        print(f"Failed to import contacts: {e}", file=sys.stderr)
+        return 1
+
+    added = []
+    for contact in imported:
+        if cm.get_contact_by_name(contact.name) or cm.get_contact_by_phone(contact.phone):
+            continue
+        contact.added_at = datetime.now().isoformat(timespec="seconds")
+        cm.contacts.append(contact)
+        added.append(contact)
+
+    if added:
+        cm.save()
+
+    if args.json:
+        print(json.dumps({
+            "found": len(imported),
+            "added": [c.to_dict() for c in added]
+        }, indent=2))
+    else:
+        print(f"Found {len(imported)} contacts, added {len(added)} new:")
+        print("-" * 40)
+        for c in added:
+            print(f"{c.name}: {c.phone}")
+
+    return 0
+
+
+def cmd_export_all(args):
+    """Export every contact's conversation to one file."""
+    mi, cm = get_interfaces()
+
+    if args.output:
+        with open(Path(args.output).expanduser(), 'w', encoding='utf-8') as f:
+            report = mi.bulk_export(cm, f, fmt=args.format, limit_per=args.limit)
+    else:
+        report = mi.bulk_export(cm, sys.stdout, fmt=args.format, limit_per=args.limit)
+
+    print(f"Exported {report['messages_exported']} messages for "
+          f"{report['contacts_exported']} contacts", file=sys.stderr)
+    for error in report['errors']:
+        print(f"  Failed: {error}", file=sys.stderr)
+
+    return 1 if report['errors'] else 0
+
+
 # =============================================================================
 # T1 COMMANDS - Advanced Features
 # =============================================================================
 
 
+def cmd_normalize_contacts(args):
+    """Canonicalize phone numbers in the contacts file."""
+    _, cm = get_interfaces()
+    if not args.dry_run and not contacts_writable(cm):
+        return 1
+
+    if args.rename_type:
+        return _rename_relationship_type(cm, args)
+
+    if args.country_code:
+        try:
+            changes = cm.apply_country_prefix(args.country_code)
+        except ValueError as e:
+            print(f"Error: {e}", file=sys.stderr)
+            return 1
+    else:
+        changes = cm.normalize_all_phones()
+    if changes and not args.dry_run:
+        cm.save()
+
+    if args.json:
+        print(json.dumps({"dry_run": args.dry_run, "changes": changes}, indent=2))
+    else:
+        if not changes:
+            print("All phone numbers are already normalized.")
+            return 0
+
+        verb = "Would normalize" if args.dry_run else "Normalized"
+        print(f"{verb} {len(changes)} phone numbers:")
+        print("-" * 40)
+        for c in changes:
+            print(f"{c['name']}: {c['old_phone']} -> {c['new_phone']}")
+
+    return 0
+
+
+def _rename_relationship_type(cm: ContactsManager, args):
+    """Apply normalize-contacts --rename-type OLD=NEW."""
+    old, sep, new = args.rename_type.partition('=')
+    old, new = old.strip(), new.strip()
+    if not sep or not old or not new:
+        print("Error: --rename-type expects OLD=NEW, e.g. close_friend='close friend'", file=sys.stderr)
+        return 1
+
+    changed = cm.rename_relationship_type(old, new)
+    if changed and not args.dry_run:
+        cm.save()
+
+    if args.json:
+        print(json.dumps({"dry_run": args.dry_run, "old": old, "new": new, "changed": changed}, indent=2))
+    else:
+        verb = "Would rename" if args.dry_run else "Renamed"
+        print(f"{verb} relationship type '{old}' to '{new}' on {changed} contacts.")
+
+    return 0
+
+
 def cmd_reactions(args):
     """Get reactions (tapbacks) from messages."""
     mi, cm = get_interfaces()
 
+    if args.guid:
+        return _print_message_reactions(mi, cm, args)
+
     phone = None
     if args.contact:
         contact = resolve_contact(cm, args.contact)
@@ -450,9 +1830,31 @@ def cmd_reactions(args):
         for r in reactions:
             emoji = r.get('reaction_emoji', '?')
             reactor = "Me" if r.get('is_from_me') else r.get('reactor_handle', 'Unknown')
-            original = r.get('original_message_preview', '')[:50]
+            original = shorten(args, r.get('original_message_preview', ''), 50)
             date = r.get('date', '')
-            print(f"{emoji} by {reactor} on \"{original}...\" ({date})")
+            print(f"{emoji} by {reactor} on \"{original}\" ({date})")
+
+    return 0
+
+
+def _print_message_reactions(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print who reacted to a single message."""
+    reactions = mi.reactions_for_message(args.guid)
+
+    if args.json:
+        print(json.dumps(reactions, indent=2, default=str))
+    else:
+        if not reactions:
+            print(f"No reactions on message {args.guid}.")
+            return 0
+
+        print(f"Reactions on {args.guid}:")
+        print("-" * 60)
+        for r in reactions:
+            handle = r['reactor_handle']
+            contact = cm.get_contact_by_phone(handle) if handle not in ('me', 'unknown') else None
+            reactor = "Me" if handle == 'me' else (contact.name if contact else handle)
+            print(f"{reactor}: {r['reaction_type']} ({r.get('date') or ''})")
 
     return 0
 
@@ -518,7 +1920,7 @@ def cmd_voice(args):
             path = v.get('attachment_path', 'N/A')
             sender = "Me" if v.get('is_from_me') else v.get('sender_handle', 'Unknown')
             size = v.get('size_bytes', 0)
-            size_str = f"{size / 1024:.1f}KB" if size else "N/A"
+            size_str = format_bytes(size) if size else "N/A"
             date = v.get('date', '')
             print(f"{path}")
             print(f"  From: {sender}, Size: {size_str}, Date: {date}")
@@ -550,7 +1952,33 @@ def cmd_thread(args):
             text = m.get('text', '[media]') or '[media]'
             date = m.get('date', '')
             is_originator = " [THREAD START]" if m.get('is_thread_originator') else ""
-            print(f"[{date}] {sender}: {text[:150]}{is_originator}")
+            print(f"[{date}] {sender}: {shorten(args, text, 150)}{is_originator}")
+
+    return 0
+
+
+def cmd_threads(args):
+    """List messages that started reply threads."""
+    mi, _ = get_interfaces()
+
+    threads = mi.conversations_with_threads(limit=args.limit)
+
+    if args.json:
+        print(json.dumps(threads, indent=2, default=str))
+    else:
+        if not threads:
+            print("No threads found.")
+            return 0
+
+        print(f"Threads ({len(threads)}):")
+        print("-" * 60)
+        for t in threads:
+            sender = "Me" if t.get('is_from_me') else t.get('sender_handle', 'Unknown')
+            text = t.get('text', '[media]') or '[media]'
+            replies = t.get('reply_count', 0)
+            print(f"[{t.get('date', '')}] {sender}: {shorten(args, text, 100)} ({replies} replies)")
+            print(f"  GUID: {t.get('guid', 'N/A')}")
+        print("\nExpand a thread with: thread --guid GUID")
 
     return 0
 
@@ -564,6 +1992,17 @@ def cmd_handles(args):
     """List all unique phone/email handles from recent messages."""
     mi, _ = get_interfaces()
 
+    if args.search:
+        matches = mi.search_handles(args.search)
+        if args.json:
+            print(json.dumps(matches, indent=2))
+        else:
+            print(f"Handles matching \"{args.search}\" ({len(matches)}):")
+            print("-" * 60)
+            for handle in matches:
+                print(handle)
+        return 0
+
     handles = mi.list_recent_handles(days=args.days, limit=args.limit)
 
     if args.json:
@@ -612,7 +2051,7 @@ def cmd_unknown(args):
             # Show sample messages if available
             messages = u.get('messages', [])
             for msg in messages[:2]:
-                text = msg.get('text', '')[:80] if msg.get('text') else '[media]'
+                text = shorten(args, msg['text'], 80) if msg.get('text') else '[media]'
                 print(f"  \"{text}\"")
 
     return 0
@@ -638,12 +2077,35 @@ def cmd_scheduled(args):
             recipient = s.get('recipient_handle', 'Unknown')
             sched_date = s.get('scheduled_date', 'N/A')
             print(f"To: {recipient}")
-            print(f"  Message: {text[:100]}")
+            print(f"  Message: {shorten(args, text, 100)}")
             print(f"  Scheduled for: {sched_date}")
 
     return 0
 
 
+def cmd_failures(args):
+    """Get sent messages that failed to deliver."""
+    mi, cm = get_interfaces()
+
+    failures = mi.delivery_failures(days=args.days, limit=args.limit)
+
+    if args.json:
+        print(json.dumps(failures, indent=2, default=str))
+    else:
+        if not failures:
+            print("No failed messages.")
+            return 0
+
+        print(f"Failed Messages ({len(failures)}):")
+        print("-" * 60)
+        for f in failures:
+            contact = cm.get_contact_by_phone(f['phone'])
+            recipient = contact.name if contact else f['phone']
+            print(f"{f.get('date') or ''} to {recipient} (error {f['error_code']}): {shorten(args, f['text'], 100)}")
+
+    return 0
+
+
 def cmd_summary(args):
     """Get conversation formatted for AI summarization."""
     mi, cm = get_interfaces()
@@ -653,6 +2115,9 @@ def cmd_summary(args):
         print(f"Contact '{args.contact}' not found.", file=sys.stderr)
         return 1
 
+    if args.max_tokens:
+        return _print_llm_context(mi, contact, args)
+
     summary = mi.get_conversation_for_summary(
         phone=contact.phone,
         days=args.days,
@@ -681,6 +2146,144 @@ def cmd_summary(args):
     return 0
 
 
+def _print_llm_context(mi: MessagesInterface, contact, args):
+    """Print recent messages trimmed to a token budget."""
+    context = mi.llm_context(contact.phone, max_tokens=args.max_tokens)
+
+    if args.json:
+        print(json.dumps(context, indent=2, default=str))
+    else:
+        print(f"Conversation Context: {contact.name}")
+        print("-" * 60)
+        print(context['analytics_summary'])
+        print(f"~{context['token_estimate']} tokens, {len(context['messages'])} messages\n")
+        for m in context['messages']:
+            sender = "Me" if m.get('is_from_me') else contact.name
+            print(f"{sender}: {m.get('text') or '[media]'}")
+
+    return 0
+
+
+def cmd_info(args):
+    """Show Messages database status and activity summaries."""
+    mi, _ = get_interfaces()
+
+    info = {
+        "messages_db_path": str(mi.messages_db_path),
+        "permissions": mi.check_permissions(),
+    }
+
+    if args.rolling_window:
+        info["rolling_window"] = mi.rolling_window_count(days=args.rolling_window)
+
+    if args.suggest_indices:
+        info["index_suggestions"] = mi.suggest_indices()
+
+    if args.json:
+        print(json.dumps(info, indent=2, default=str))
+    else:
+        permissions = info["permissions"]
+        print("iMessage Gateway Info")
+        print("-" * 40)
+        print(f"Database: {info['messages_db_path']}")
+        print(f"Database accessible: {'yes' if permissions.get('messages_db_accessible') else 'no'}")
+
+        window = info.get("rolling_window")
+        if window:
+            print(f"\nLast {window['period_days']} days:")
+            print(f"  Messages: {window['total']} ({window['daily_average']}/day)")
+            if window.get('peak_day'):
+                print(f"  Busiest day: {window['peak_day']} ({window['peak_count']} messages)")
+
+        if args.suggest_indices:
+            suggestions = info["index_suggestions"]
+            if not suggestions:
+                print("\nNo missing indices found for common queries.")
+            else:
+                print("\nSuggested indices (apply to a backup, with Messages closed):")
+                for suggestion in suggestions:
+                    print(f"  -- {suggestion['rationale']}")
+                    print(f"  {suggestion['create_statement']}")
+
+    return 0
+
+
+def cmd_status(args):
+    """Show whether iMessage is ready and how many conversations are unread."""
+    mi, cm = get_interfaces()
+
+    if args.contact:
+        return _print_contact_status(mi, cm, args)
+
+    if args.dashboard:
+        return _print_dashboard(mi, cm, args)
+
+    status = {
+        "imessage_available": mi.is_imessage_available(),
+        "unread_threads": mi.unread_thread_count(),
+    }
+
+    if args.json:
+        print(json.dumps(status, indent=2, default=str))
+    else:
+        print("iMessage Status")
+        print("-" * 40)
+        print(f"iMessage available: {'yes' if status['imessage_available'] else 'no'}")
+        print(f"Unread conversations: {status['unread_threads']}")
+
+    return 0
+
+
+def _print_dashboard(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print database-wide counts for an at-a-glance snapshot."""
+    summary = mi.global_summary()
+
+    if args.json:
+        print(json.dumps(summary, indent=2, default=str))
+        return 0
+
+    handle = summary['most_active_contact_handle']
+    contact = cm.get_contact_by_phone(handle) if handle else None
+
+    print("iMessage Dashboard")
+    print("-" * 40)
+    print(f"Messages: {summary['total_messages']:,} total, {summary['messages_today']} today, "
+          f"{summary['messages_this_week']} this week")
+    print(f"Conversations: {summary['total_conversations']:,}")
+    print(f"Most active (30 days): {contact.name if contact else handle or 'N/A'}")
+    print(f"Pending follow-ups (7 days): {summary['pending_followups']}")
+    print(f"Unread conversations: {summary['unread_thread_count']}")
+
+    return 0
+
+
+def _print_contact_status(mi: MessagesInterface, cm: ContactsManager, args):
+    """Print whether a contact's handle is still in active use."""
+    contact = resolve_contact(cm, args.contact)
+    if not contact:
+        print(f"Contact '{args.contact}' not found.", file=sys.stderr)
+        return 1
+
+    reachability = mi.contact_reachability(contact.phone, recency_days=args.days)
+
+    if args.json:
+        print(json.dumps({"name": contact.name, "phone": contact.phone, **reachability}, indent=2))
+    else:
+        descriptions = {
+            "active": "active - messages both ways recently",
+            "one_way": ("one-way - only your messages recently, no replies"
+                        if reachability['sent_recent'] else
+                        "one-way - only their messages recently, no replies from you"),
+            "inactive": "inactive - no recent messages",
+            "unknown": "unknown - no messages with this handle",
+        }
+        print(f"{contact.name} ({contact.phone}): {descriptions[reachability['status']]}")
+        print(f"Last {args.days} days: {reachability['sent_recent']} sent, "
+              f"{reachability['received_recent']} received")
+
+    return 0
+
+
 # =============================================================================
 # RAG COMMANDS - Semantic Search & Knowledge Base
 # =============================================================================
@@ -795,7 +2398,7 @@ def cmd_search(args):
                 source = result.get('source', 'unknown')
                 title = result.get('title') or result.get('context_id', '')[:30]
                 timestamp = result.get('timestamp', '')[:10] if result.get('timestamp') else ''
-                text = result.get('text', '')[:200]
+                text = shorten(args, result.get('text', ''), 200)
 
                 print(f"\n[{i}] [{source}] {title} | {timestamp} | {score:.0f}% match")
                 print(f"    {text}...")
@@ -969,12 +2572,48 @@ Examples:
         """
     )
 
+    parser.add_argument('--max-text-length', dest='max_text_length', type=int, metavar='N',
+                        help='Truncate message text in messages, recent and followup output '
+                             'to N characters (0 = no truncation)')
+
+    parser.add_argument('--verbose', '-v', action='store_true',
+                        help='Report progress to stderr while opening the Messages database')
+    parser.add_argument('--backup-dir', dest='backup_dir', metavar='PATH',
+                        help='Query the messages database in an iTunes/Finder or iCloud device backup '
+                             '(a MobileSync/Backup/{udid} directory) instead of chat.db')
+
     subparsers = parser.add_subparsers(dest='command', help='Command to run')
 
     # find command (keyword search in messages)
     p_find = subparsers.add_parser('find', help='Find messages with a contact (keyword search)')
-    p_find.add_argument('contact', help='Contact name (fuzzy matched)')
+    p_find.add_argument('contact', nargs='?', help='Contact name (fuzzy matched)')
     p_find.add_argument('--query', '-q', help='Text to search for in messages')
+    p_find.add_argument('--contact-list', dest='contact_list', metavar='FILE',
+                        help='Search every contact in FILE (one name or phone per line)')
+    p_find.add_argument('--groups-only', dest='groups_only', action='store_true',
+                        help='Search --query across all group chats instead of one contact')
+    p_find.add_argument('--mentioned-contacts', action='store_true',
+                        help='List contacts mentioned by name in the conversation')
+    p_find.add_argument('--proximity', nargs='+', metavar='TERM',
+                        help='Match messages where all TERMs appear within --distance words '
+                             'of each other, e.g. --proximity meeting Tuesday '
+                             '(runs FTS5 NEAR("meeting" "Tuesday", N))')
+    p_find.add_argument('--distance', type=int, default=5, choices=range(0, 101), metavar='N',
+                        help='Max words between --proximity terms (0-100, default: 5)')
+    p_find.add_argument('--whole-word', dest='whole_word', action='store_true',
+                        help='Match --query as whole words ("meeting" skips "meetings")')
+    p_find.add_argument('--min-length', dest='min_length', type=int, metavar='N',
+                        help='Only messages of at least N characters (contact optional)')
+    p_find.add_argument('--max-length', dest='max_length', type=int, metavar='N',
+                        help='Only messages of at most N characters (contact optional)')
+    p_find.add_argument('--highlight', action='store_true',
+                        help='Include match_positions for --query matches (bold in a terminal)')
+    p_find.add_argument('--has-url', dest='has_url', action='store_true',
+                        help='Only show messages containing http(s) links')
+    p_find.add_argument('--from', dest='date_from', type=parse_date_arg, metavar='DATE',
+                        help='With --query, only search messages on or after DATE (YYYY-MM-DD)')
+    p_find.add_argument('--to', dest='date_to', type=lambda v: parse_date_arg(v, end_of_day=True),
+                        metavar='DATE', help='With --query, only search messages on or before DATE')
     p_find.add_argument('--limit', '-l', type=int, default=30, choices=range(1, 501), metavar='N',
                         help='Max messages to return (1-500, default: 30)')
     p_find.add_argument('--json', action='store_true', help='Output as JSON')
@@ -986,12 +2625,39 @@ Examples:
     p_messages.add_argument('--limit', '-l', type=int, default=20, choices=range(1, 501), metavar='N',
                             help='Max messages (1-500, default: 20)')
     p_messages.add_argument('--json', action='store_true', help='Output as JSON')
+    p_messages.add_argument('--export-text', dest='export_text', metavar='PATH',
+                            help='Write a plain text transcript to PATH instead of printing')
+    p_messages.add_argument('--export-csv', dest='export_csv', metavar='PATH',
+                            help='Write messages to a CSV file instead of printing')
+    p_messages.add_argument('--group-by-date', dest='group_by_date', action='store_true',
+                            help='Insert a header line for each calendar date')
+    p_messages.add_argument('--timeline', action='store_true',
+                            help='Show oldest first with day boundaries and gap markers')
+    p_messages.add_argument('--estimate-size', dest='estimate_size', action='store_true',
+                            help='Estimate the memory needed to load the whole conversation')
+    p_messages.add_argument('--include-reactions', dest='include_reactions', action='store_true',
+                            help='Show tapbacks indented under the message they react to')
+    p_messages.add_argument('--first-received', dest='first_received', action='store_true',
+                            help='Show the first message the contact ever sent you')
+    p_messages.add_argument('--sample', type=int, choices=range(1, 501), metavar='N',
+                            help='Show N randomly chosen messages (oldest first) instead of the latest')
+    p_messages.add_argument('--fast-sample', dest='fast_sample', action='store_true',
+                            help='With --sample, pick by random ROWID instead of scanning the '
+                                 'whole conversation (faster on large databases, less uniform)')
+    p_messages.add_argument('--paged', action='store_true',
+                            help='Show the newest page with a token for fetching older messages')
+    p_messages.add_argument('--page-token', dest='page_token', metavar='TOKEN',
+                            help='Show the page a --paged token points to')
+    add_color_args(p_messages)
     p_messages.set_defaults(func=cmd_messages)
 
     # recent command
     p_recent = subparsers.add_parser('recent', help='Get recent conversations')
     p_recent.add_argument('--limit', '-l', type=int, default=10, choices=range(1, 501), metavar='N',
                           help='Max conversations (1-500, default: 10)')
+    p_recent.add_argument('--handle-pattern', dest='handle_pattern', metavar='PATTERN',
+                          help='Only conversations whose handle contains PATTERN '
+                               '(e.g. "gmail.com", "+44")')
     p_recent.add_argument('--json', action='store_true', help='Output as JSON')
     p_recent.set_defaults(func=cmd_recent)
 
@@ -1000,23 +2666,73 @@ Examples:
     p_unread.add_argument('--limit', '-l', type=int, default=20, choices=range(1, 501), metavar='N',
                           help='Max messages (1-500, default: 20)')
     p_unread.add_argument('--json', action='store_true', help='Output as JSON')
+    add_color_args(p_unread)
     p_unread.set_defaults(func=cmd_unread)
 
+    # watch command
+    p_watch = subparsers.add_parser('watch', help='Print new messages as they arrive')
+    p_watch.add_argument('contact', nargs='?', help='Only show messages with this contact')
+    p_watch.add_argument('--interval', '-i', type=watch_interval_arg, default=2.0, metavar='SECS',
+                         help=f'Seconds between database polls (default: 2, minimum: {MIN_WATCH_INTERVAL})')
+    p_watch.add_argument('--json', action='store_true', help='Output one JSON object per line')
+    add_color_args(p_watch)
+    p_watch.set_defaults(func=cmd_watch)
+
     # send command
     p_send = subparsers.add_parser('send', help='Send a message')
     p_send.add_argument('contact', help='Contact name')
     p_send.add_argument('message', nargs='+', help='Message to send')
+    p_send.add_argument('--notify-on-send', action='store_true',
+                        help='Show a macOS notification once the message is sent')
+    p_send.add_argument('--dismiss-followup', action='store_true',
+                        help='Hide follow-ups for this contact for 7 days after sending')
+    p_send.add_argument('--confirm', action='store_true',
+                        help='Wait for chat.db to show the message delivered (exit 2 if not confirmed)')
+    p_send.add_argument('--timeout', type=int, default=10, choices=range(1, 301), metavar='N',
+                        help='Seconds to wait for --confirm (1-300, default: 10)')
     p_send.set_defaults(func=cmd_send)
 
     # send-by-phone command
     p_send_phone = subparsers.add_parser('send-by-phone', help='Send message directly to phone number')
     p_send_phone.add_argument('phone', help='Phone number (e.g., +14155551234)')
     p_send_phone.add_argument('message', nargs='+', help='Message to send')
+    p_send_phone.add_argument('--notify-on-send', action='store_true',
+                              help='Show a macOS notification once the message is sent')
     p_send_phone.add_argument('--json', action='store_true', help='Output as JSON')
     p_send_phone.set_defaults(func=cmd_send_by_phone)
 
     # contacts command
     p_contacts = subparsers.add_parser('contacts', help='List all contacts')
+    p_contacts.add_argument('--added-since', dest='added_since', type=int, metavar='DAYS',
+                            help='Only show contacts added in the last DAYS days')
+    p_contacts.add_argument('--relationship-types', dest='relationship_types', action='store_true',
+                            help='List the relationship types used in the contacts file')
+    p_contacts.add_argument('--type', '-t', metavar='TYPE',
+                            help='Only show contacts with this relationship type (e.g. family)')
+    p_contacts.add_argument('--name-contains', dest='name_contains', metavar='TEXT',
+                            help='Only show contacts whose name contains TEXT')
+    p_contacts.add_argument('--phone-prefix', dest='phone_prefix', metavar='PREFIX',
+                            help='Only show contacts whose phone starts with PREFIX (e.g. +44)')
+    p_contacts.add_argument('--contact', '-c', metavar='NAME',
+                            help='Only show this contact')
+    p_contacts.add_argument('--first-seen', dest='first_seen', action='store_true',
+                            help='Show when each contact first appeared in Messages')
+    p_contacts.add_argument('--handles', action='store_true',
+                            help='Show every handle ID in chat.db that matches each phone, in any format')
+    p_contacts.add_argument('--fuzzy', metavar='QUERY',
+                            help='Rank contacts by name similarity to QUERY (for disambiguation)')
+    p_contacts.add_argument('--limit', '-l', type=int, default=10, choices=range(1, 101), metavar='N',
+                            help='Max --fuzzy candidates (1-100, default: 10)')
+    p_contacts.add_argument('--without-notes', dest='without_notes', action='store_true',
+                            help='Only show contacts that have no notes')
+    p_contacts.add_argument('--has-email', dest='has_email', action='store_true',
+                            help='Only show contacts that have an email address')
+    p_contacts.add_argument('--export-to-macos', dest='export_to_macos', action='store_true',
+                            help='Add the listed contacts to macOS Contacts.app (skips names already there)')
+    p_contacts.add_argument('--show-metadata', action='store_true',
+                            help="Annotate contacts with their handle's service and country")
+    p_contacts.add_argument('--sort-by', dest='sort_by', choices=['file', 'last-contacted'], default='file',
+                            help='Order contacts as in the file (default) or by most recent message')
     p_contacts.add_argument('--json', action='store_true', help='Output as JSON')
     p_contacts.set_defaults(func=cmd_contacts)
 
@@ -1025,18 +2741,91 @@ Examples:
     p_analytics.add_argument('contact', nargs='?', help='Contact name (optional)')
     p_analytics.add_argument('--days', '-d', type=int, default=30, choices=range(1, 366), metavar='N',
                              help='Days to analyze (1-365, default: 30)')
-    p_analytics.add_argument('--json', action='store_true', help='Output as JSON')
+    p_analytics.add_argument('--chat-guid', dest='chat_guid',
+                             help='Analyze a group chat by GUID or group ID (see: groups)')
+    p_analytics.add_argument('--by-service', dest='by_service', action='store_true',
+                             help='Split sent/received counts into iMessage and SMS')
+    p_analytics.add_argument('--edited-count', dest='edited_count', action='store_true',
+                             help='Include how many messages were edited (macOS Ventura+)')
+    p_analytics.add_argument('--percentiles', action='store_true',
+                             help='Include response time mean, median and p50/p75/p95 for both sides')
+    p_analytics.add_argument('--emoji-sentiment', dest='emoji_sentiment', action='store_true',
+                             help='Count messages with positive vs negative emoji (requires a contact)')
+    p_analytics.add_argument('--longest-gap', dest='longest_gap', action='store_true',
+                             help="Include the longest silence in the contact's full history")
+    p_analytics.add_argument('--pair', nargs=2, metavar=('CONTACT_A', 'CONTACT_B'),
+                             help='Count how often two contacts mention each other')
+    p_analytics.add_argument('--all-contacts', dest='all_contacts', action='store_true',
+                             help='Show message counts for every contact')
+    p_analytics.add_argument('--full', action='store_true',
+                             help='Show every analytics view for a contact (best with --json)')
+    p_analytics.add_argument('--timeline', dest='stats_timeline', action='store_true',
+                             help='Show sent/received counts and reply speed per period for a contact')
+    p_analytics.add_argument('--bucket-days', dest='bucket_days', type=int, choices=range(1, 366),
+                             metavar='N', help='With --timeline, use rolling N-day windows ending now '
+                                               'instead of calendar periods (1-365)')
+    p_analytics.add_argument('--topics', type=int, choices=range(1, 21), metavar='N',
+                             help='Show the N main topics of a conversation, as keyword clusters (1-20)')
+    p_analytics.add_argument('--duplicates', action='store_true',
+                             help='Find messages with a contact that were sent twice in quick succession')
+    p_analytics.add_argument('--duplicate-window', type=int, default=60, choices=range(1, 3601),
+                             metavar='SECS', help='With --duplicates, maximum gap between copies (1-3600, default: 60)')
+    p_analytics.add_argument('--heatmap', action='store_true',
+                             help='Show message counts by day of week and hour (7x24 grid)')
+    p_analytics.add_argument('--sentiment-trend', dest='sentiment_trend', action='store_true',
+                             help='Show the share of positive messages per period for a contact')
+    p_analytics.add_argument('--period', choices=MessagesInterface.PERIOD_UNITS, default='weekly',
+                             help='Period length for --timeline and --sentiment-trend (default: weekly)')
+    p_analytics.add_argument('--periods', type=int, default=12, choices=range(1, 366), metavar='N',
+                             help='Number of periods for --timeline and --sentiment-trend '
+                                  '(1-365, default: 12)')
+    p_analytics.add_argument('--no-chart', dest='no_chart', action='store_true',
+                             help='Print plain key-value lines instead of bar charts')
+    analytics_json = p_analytics.add_mutually_exclusive_group()
+    analytics_json.add_argument('--json', action='store_const', const='pretty',
+                                help='Output as indented JSON')
+    analytics_json.add_argument('--json-compact', dest='json', action='store_const', const='compact',
+                                help='Output as compact single-line JSON (smaller for large exports)')
     p_analytics.set_defaults(func=cmd_analytics)
 
+    # compare command
+    p_compare = subparsers.add_parser('compare', help='Compare communication patterns with two contacts')
+    p_compare.add_argument('--contact-a', dest='contact_a', required=True, metavar='NAME',
+                           help='First contact')
+    p_compare.add_argument('--contact-b', dest='contact_b', required=True, metavar='NAME',
+                           help='Second contact')
+    p_compare.add_argument('--days', '-d', type=int, default=30, choices=range(1, 366), metavar='N',
+                           help='Days to analyze (1-365, default: 30)')
+    p_compare.add_argument('--json', action='store_true', help='Output as JSON')
+    p_compare.set_defaults(func=cmd_compare)
+
     # followup command
     p_followup = subparsers.add_parser('followup', help='Detect messages needing follow-up')
     p_followup.add_argument('--days', '-d', type=int, default=7, choices=range(1, 366), metavar='N',
                             help='Days to look back (1-365, default: 7)')
     p_followup.add_argument('--stale', '-s', type=int, default=2, choices=range(1, 366), metavar='N',
                             help='Min stale days (1-365, default: 2)')
+    p_followup.add_argument('--context', action='store_true',
+                            help='Include the last few messages of each conversation')
+    p_followup.add_argument('--unknown-only', dest='unknown_only', action='store_true',
+                            help='Only show follow-ups from handles not in your contacts')
     p_followup.add_argument('--json', action='store_true', help='Output as JSON')
     p_followup.set_defaults(func=cmd_followup)
 
+    # sent command
+    p_sent = subparsers.add_parser('sent', help='Show messages you sent to a contact')
+    p_sent.add_argument('--contact', '-c', required=True, help='Contact name')
+    p_sent.add_argument('--unacknowledged', action='store_true',
+                        help='Only show messages that never got a reply')
+    p_sent.add_argument('--wait-hours', type=int, default=24, choices=range(1, 721), metavar='N',
+                        help='Hours to wait for a reply before flagging (1-720, default: 24)')
+    p_sent.add_argument('--days', '-d', type=int, default=30, choices=range(1, 366), metavar='N',
+                        help='Days to look back with --unacknowledged (1-365, default: 30)')
+    p_sent.add_argument('--limit', '-l', type=int, default=20, choices=range(1, 501), metavar='N',
+                        help='Max messages to scan without --unacknowledged (1-500, default: 20)')
+    p_sent.add_argument('--json', action='store_true', help='Output as JSON')
+    p_sent.set_defaults(func=cmd_sent)
+
     # =========================================================================
     # T0 COMMANDS - Core Features
     # =========================================================================
@@ -1054,13 +2843,28 @@ Examples:
     p_group_msg.add_argument('--participant', '-p', help='Filter by participant phone/email')
     p_group_msg.add_argument('--limit', '-l', type=int, default=50, choices=range(1, 501), metavar='N',
                              help='Max messages (1-500, default: 50)')
+    p_group_msg.add_argument('--breakdown', action='store_true',
+                             help='Also show message counts per participant (requires --group-id)')
+    p_group_msg.add_argument('--reply-counts', dest='reply_counts', action='store_true',
+                             help='Annotate each message with its number of inline replies')
+    p_group_msg.add_argument('--days', '-d', type=int, choices=range(1, 366), metavar='N',
+                             help='Limit --breakdown to the last N days (1-365, default: all time)')
     p_group_msg.add_argument('--json', action='store_true', help='Output as JSON')
+    add_color_args(p_group_msg)
     p_group_msg.set_defaults(func=cmd_group_messages)
 
     # attachments command
     p_attach = subparsers.add_parser('attachments', help='Get attachments (photos, videos, files)')
     p_attach.add_argument('contact', nargs='?', help='Contact name (optional)')
     p_attach.add_argument('--type', '-t', help='MIME type filter (e.g., "image/", "video/")')
+    p_attach.add_argument('--total-size', action='store_true',
+                          help='Show total attachment storage instead of listing files')
+    p_attach.add_argument('--data-usage', dest='data_usage', action='store_true',
+                          help="Break down a contact's attachment storage by direction and type")
+    p_attach.add_argument('--audio-summary', dest='audio_summary', action='store_true',
+                          help='Count voice memos sent and received (requires a contact)')
+    p_attach.add_argument('--list-types', dest='list_types', action='store_true',
+                          help='List the distinct MIME types instead of attachments')
     p_attach.add_argument('--limit', '-l', type=int, default=50, choices=range(1, 501), metavar='N',
                           help='Max attachments (1-500, default: 50)')
     p_attach.add_argument('--json', action='store_true', help='Output as JSON')
@@ -1076,15 +2880,64 @@ Examples:
     p_add.add_argument('--notes', '-n', help='Notes about the contact')
     p_add.set_defaults(func=cmd_add_contact)
 
+    # edit-contact command
+    p_edit = subparsers.add_parser('edit-contact', help='Edit an existing contact')
+    p_edit.add_argument('name', nargs='?', help='Current contact name (same as --name)')
+    p_edit.add_argument('--name', dest='name_flag', metavar='NAME',
+                        help='Current contact name (exact match)')
+    p_edit.add_argument('--new-name', dest='new_name', help='Rename the contact')
+    p_edit.add_argument('--phone', '-p', help='New phone number')
+    p_edit.add_argument('--relationship-type', '--relationship', '-r', dest='relationship',
+                        choices=['friend', 'family', 'colleague', 'professional', 'other'],
+                        help='New relationship type')
+    p_edit.add_argument('--notes', '-n', help='Replace the notes')
+    p_edit.add_argument('--add-alias', dest='add_alias', metavar='ALIAS',
+                        help='Add an alternative name the contact can be found by')
+    p_edit.set_defaults(func=cmd_edit_contact)
+
+    # import-contacts command
+    p_import = subparsers.add_parser('import-contacts', help='Import contacts from macOS Contacts.app')
+    p_import.add_argument('--format', '-f', choices=['applescript', 'google-csv'], default='applescript',
+                          help='Import method (default: applescript)')
+    p_import.add_argument('--file', metavar='PATH',
+                          help='CSV exported from Google Contacts (for --format google-csv)')
+    p_import.add_argument('--json', action='store_true', help='Output as JSON')
+    p_import.set_defaults(func=cmd_import_contacts)
+
+    # export-all command
+    p_export_all = subparsers.add_parser('export-all', help="Export every contact's conversation")
+    p_export_all.add_argument('--format', '-f', choices=MessagesInterface.EXPORT_FORMATS, default='json',
+                              help='json (one array), ndjson (one contact per line) or markdown '
+                                   '(default: json)')
+    p_export_all.add_argument('--output', '-o', metavar='PATH',
+                              help='File to write (default: stdout)')
+    p_export_all.add_argument('--limit', '-l', type=int, default=1000, choices=range(1, 100_001),
+                              metavar='N', help='Max messages per contact (1-100000, default: 1000)')
+    p_export_all.set_defaults(func=cmd_export_all)
+
     # =========================================================================
     # T1 COMMANDS - Advanced Features
     # =========================================================================
 
+    # normalize-contacts command
+    p_normalize = subparsers.add_parser('normalize-contacts',
+                                        help='Canonicalize phone numbers in the contacts file')
+    p_normalize.add_argument('--dry-run', action='store_true',
+                             help='Show what would change without writing the file')
+    p_normalize.add_argument('--rename-type', dest='rename_type', metavar='OLD=NEW',
+                             help='Instead, change relationship type OLD to NEW on every contact')
+    p_normalize.add_argument('--country-code', dest='country_code', metavar='CODE',
+                             help='Instead, add +CODE to numbers saved in national format '
+                                  '(e.g. 44 turns 07911123456 into +447911123456)')
+    p_normalize.add_argument('--json', action='store_true', help='Output as JSON')
+    p_normalize.set_defaults(func=cmd_normalize_contacts)
+
     # reactions command
     p_react = subparsers.add_parser('reactions', help='Get reactions (tapbacks) from messages')
     p_react.add_argument('contact', nargs='?', help='Contact name (optional)')
     p_react.add_argument('--limit', '-l', type=int, default=100, choices=range(1, 501), metavar='N',
                          help='Max reactions (1-500, default: 100)')
+    p_react.add_argument('--guid', help='Show who reacted to the message with this GUID')
     p_react.add_argument('--json', action='store_true', help='Output as JSON')
     p_react.set_defaults(func=cmd_reactions)
 
@@ -1116,6 +2969,13 @@ Examples:
     p_thread.add_argument('--json', action='store_true', help='Output as JSON')
     p_thread.set_defaults(func=cmd_thread)
 
+    # threads command
+    p_threads = subparsers.add_parser('threads', help='List messages that started reply threads')
+    p_threads.add_argument('--limit', '-l', type=int, default=50, choices=range(1, 501), metavar='N',
+                           help='Max threads (1-500, default: 50)')
+    p_threads.add_argument('--json', action='store_true', help='Output as JSON')
+    p_threads.set_defaults(func=cmd_threads)
+
     # =========================================================================
     # T2 COMMANDS - Discovery Features
     # =========================================================================
@@ -1126,6 +2986,8 @@ Examples:
                            help='Days to look back (1-365, default: 30)')
     p_handles.add_argument('--limit', '-l', type=int, default=100, choices=range(1, 501), metavar='N',
                            help='Max handles (1-500, default: 100)')
+    p_handles.add_argument('--search', '-s', metavar='PATTERN',
+                           help='Find handles containing PATTERN (case-insensitive, any date)')
     p_handles.add_argument('--json', action='store_true', help='Output as JSON')
     p_handles.set_defaults(func=cmd_handles)
 
@@ -1143,6 +3005,15 @@ Examples:
     p_sched.add_argument('--json', action='store_true', help='Output as JSON')
     p_sched.set_defaults(func=cmd_scheduled)
 
+    # failures command
+    p_failures = subparsers.add_parser('failures', help='Get sent messages that failed to deliver')
+    p_failures.add_argument('--days', '-d', type=int, choices=range(1, 3651), metavar='N',
+                            help='Days to look back (1-3650, default: all time)')
+    p_failures.add_argument('--limit', '-l', type=int, default=100, choices=range(1, 501), metavar='N',
+                            help='Max messages (1-500, default: 100)')
+    p_failures.add_argument('--json', action='store_true', help='Output as JSON')
+    p_failures.set_defaults(func=cmd_failures)
+
     # summary command
     p_summary = subparsers.add_parser('summary', help='Get conversation formatted for AI summarization')
     p_summary.add_argument('contact', help='Contact name')
@@ -1150,9 +3021,32 @@ Examples:
                            help='Days to include (1-365)')
     p_summary.add_argument('--limit', '-l', type=int, default=200, choices=range(1, 501), metavar='N',
                            help='Max messages (1-500, default: 200)')
+    p_summary.add_argument('--max-tokens', dest='max_tokens', type=int, choices=range(1, 1_000_001),
+                           metavar='N',
+                           help='Instead, fit the most recent messages into ~N tokens for an LLM '
+                                '(estimated at 4 characters per token)')
     p_summary.add_argument('--json', action='store_true', help='Output as JSON')
     p_summary.set_defaults(func=cmd_summary)
 
+    # info command
+    p_info = subparsers.add_parser('info', help='Show database status and activity summaries')
+    p_info.add_argument('--rolling-window', type=int, choices=range(1, 366), metavar='N',
+                        help='Summarize message volume over the last N days (1-365)')
+    p_info.add_argument('--suggest-indices', dest='suggest_indices', action='store_true',
+                        help='Check common query plans and print CREATE INDEX suggestions')
+    p_info.add_argument('--json', action='store_true', help='Output as JSON')
+    p_info.set_defaults(func=cmd_info)
+
+    # status command
+    p_status = subparsers.add_parser('status', help='Show iMessage availability and unread conversations')
+    p_status.add_argument('--contact', '-c', help="Check whether a contact's handle is still active")
+    p_status.add_argument('--dashboard', action='store_true',
+                          help='Show message, conversation, follow-up and unread totals at a glance')
+    p_status.add_argument('--days', '-d', type=int, default=30, choices=range(1, 366), metavar='N',
+                          help='Days that count as recent for --contact (1-365, default: 30)')
+    p_status.add_argument('--json', action='store_true', help='Output as JSON')
+    p_status.set_defaults(func=cmd_status)
+
     # =========================================================================
     # RAG COMMANDS - Semantic Search & Knowledge Base
     # =========================================================================
@@ -1220,6 +3114,11 @@ Examples:
         parser.print_help()
         return 1
 
+    global OPEN_PROGRESS, BACKUP_DIR
+    if args.verbose:
+        OPEN_PROGRESS = print_open_progress
+    BACKUP_DIR = args.backup_dir
+
     return args.func(args)
 
 
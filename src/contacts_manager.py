@@ -5,13 +5,60 @@ Sprint 1: Basic contact lookup from JSON config
 Sprint 2: macOS Contacts sync, fuzzy matching, DB integration
 """
 
+import csv
 import json
 import logging
+import os
+import subprocess
+from dataclasses import dataclass
+from datetime import datetime, timedelta
 from pathlib import Path
-from typing import Optional, List, Dict
+from typing import Optional, List, Dict, Tuple
+
+from src.utils import applescript_list_to_list, escape_applescript_string, jaro_winkler
 
 logger = logging.getLogger(__name__)
 
+# Environment variable holding a contacts JSON document (not a path)
+CONTACTS_JSON_ENV = "IMESSAGE_CONTACTS_JSON"
+
+# Written to new contacts files so future format changes can be detected
+CONTACTS_SCHEMA_VERSION = 1
+
+# Returns a flat {name, label, number, ...} list with one triple per phone in
+# Contacts.app; run with `osascript -ss` so names with commas stay quoted
+CONTACTS_APPLESCRIPT = '''
+set output to {}
+tell application "Contacts"
+    repeat with p in people
+        set personName to name of p
+        repeat with ph in phones of p
+            set phoneLabel to ""
+            try
+                set phoneLabel to label of ph
+            end try
+            set end of output to personName
+            set end of output to phoneLabel
+            set end of output to (value of ph)
+        end repeat
+    end repeat
+end tell
+return output
+'''
+
+# Dialling prefixes that precede a country code ("00" in most of the world, "011" from NANP)
+INTERNATIONAL_DIAL_PREFIXES = ("00", "011")
+
+# Digit counts of national-format numbers with a trunk 0, e.g. UK "07911123456"
+NATIONAL_TRUNK_LENGTHS = (10, 11)
+
+# Google Contacts phone types and the relationship_type they imply
+GOOGLE_PHONE_TYPES = {
+    "mobile": "other",
+    "work": "colleague",
+    "home": "family",
+}
+
 
 class Contact:
     """Represents a contact with messaging information."""
@@ -21,23 +68,65 @@ class Contact:
         name: str,
         phone: str,
         relationship_type: str = "other",
-        notes: str = ""
+        notes: str = "",
+        added_at: Optional[str] = None,
+        aliases: Optional[List[str]] = None,
+        email: Optional[str] = None
     ):
         self.name = name
         self.phone = phone
         self.relationship_type = relationship_type
         self.notes = notes
+        self.added_at = added_at  # ISO-8601, set when added via add_contact
+        self.aliases = aliases or []  # Alternative names, e.g. nicknames
+        self.email = email  # Apple ID email, if iMessage reaches them by email
 
     def __repr__(self):
         return f"Contact(name='{self.name}', phone='{self.phone}')"
 
     def to_dict(self) -> dict:
-        return {
+        data = {
             "name": self.name,
             "phone": self.phone,
             "relationship_type": self.relationship_type,
             "notes": self.notes
         }
+        if self.added_at:
+            data["added_at"] = self.added_at
+        if self.aliases:
+            data["aliases"] = self.aliases
+        if self.email:
+            data["email"] = self.email
+        return data
+
+
+@dataclass
+class SearchCriteria:
+    """
+    Filters for ContactsManager.search. Every field that is set must match.
+
+    name_contains and relationship_type are case-insensitive. phone_prefix
+    is compared with formatting characters (spaces, dashes, dots,
+    parentheses) removed from both sides, so "+44" or "415" work.
+    """
+    name_contains: Optional[str] = None
+    phone_prefix: Optional[str] = None
+    relationship_type: Optional[str] = None
+
+
+@dataclass
+class ContactUpdate:
+    """
+    Changes for ContactsManager.update_contact. Unset fields are left as-is.
+
+    add_alias appends an alternative name that get_contact_by_name will
+    also match; adding an existing alias is a no-op.
+    """
+    new_name: Optional[str] = None
+    new_phone: Optional[str] = None
+    new_relationship_type: Optional[str] = None
+    new_notes: Optional[str] = None
+    add_alias: Optional[str] = None
 
 
 class ContactsManager:
@@ -68,29 +157,185 @@ class ContactsManager:
             return
 
         try:
-            with open(self.config_path) as f:
-                data = json.load(f)
-
-            contacts_data = data.get("contacts", [])
-            self.contacts = [
-                Contact(
-                    name=c["name"],
-                    phone=c["phone"],
-                    relationship_type=c.get("relationship_type", "other"),
-                    notes=c.get("notes", "")
-                )
-                for c in contacts_data
-            ]
-
+            self.contacts = self._read_contacts()
             logger.info(f"Loaded {len(self.contacts)} contacts from config")
 
         except Exception as e:
             logger.error(f"Error loading contacts: {e}")
             self.contacts = []
 
+    @classmethod
+    def load_or_create(cls, config_path: str = "config/contacts.json") -> "ContactsManager":
+        """
+        Load contacts, first creating an empty contacts file if there is none.
+
+        The new file is {"schema_version": 1, "contacts": []}, so later
+        loads find it instead of warning again. Unlike the constructor,
+        which logs errors and starts empty, problems are raised.
+
+        Args:
+            config_path: Path to contacts configuration file
+
+        Returns:
+            ContactsManager instance
+
+        Raises:
+            OSError: If the file can't be created or read
+            ValueError: If the file is not valid contacts JSON
+        """
+        path = Path(config_path)
+        if not path.exists():
+            path.parent.mkdir(parents=True, exist_ok=True)
+            with open(path, 'w') as f:
+                json.dump({"schema_version": CONTACTS_SCHEMA_VERSION, "contacts": []}, f, indent=2)
+            logger.info(f"Created empty contacts file at {path}")
+
+        # Skip __init__ so read errors propagate instead of being logged
+        manager = cls.__new__(cls)
+        manager.config_path = path
+        try:
+            manager.contacts = manager._read_contacts()
+        except (KeyError, TypeError, AttributeError) as e:
+            raise ValueError(f"Invalid contacts config {path}: {e}") from e
+
+        logger.info(f"Loaded {len(manager.contacts)} contacts from config")
+        return manager
+
+    @classmethod
+    def load_from_env(cls, default_path: str = "config/contacts.json") -> "ContactsManager":
+        """
+        Create a manager from the IMESSAGE_CONTACTS_JSON environment variable.
+
+        Lets test harnesses and MCP hosts inject contacts without touching
+        the filesystem. The variable holds the JSON itself, either a
+        {"contacts": [...]} document or a bare list. Without the variable
+        this is the same as ContactsManager(default_path).
+
+        An injected manager is read-only: it has no config_path, and
+        saving methods raise instead of overwriting the contacts file
+        with the injected list.
+
+        Args:
+            default_path: Config file to load when the variable is unset
+
+        Returns:
+            ContactsManager instance
+
+        Raises:
+            ValueError: If the variable is set but is not valid contacts JSON
+        """
+        raw = os.environ.get(CONTACTS_JSON_ENV)
+        if raw is None:
+            return cls(default_path)
+
+        try:
+            manager = cls.from_json_string(raw, config_path=None)
+        except ValueError as e:
+            raise ValueError(f"Invalid {CONTACTS_JSON_ENV}: {e}") from e
+
+        logger.info(f"Loaded {len(manager.contacts)} contacts from {CONTACTS_JSON_ENV}")
+        return manager
+
+    @classmethod
+    def from_json_string(
+        cls, json_str: str, config_path: Optional[str] = "config/contacts.json"
+    ) -> "ContactsManager":
+        """
+        Create a manager from contacts JSON held in memory.
+
+        Accepts the contacts.json format ({"contacts": [...]}) or a bare
+        list. Nothing is read from or written to disk until add_contact
+        or another saving method is called.
+
+        Args:
+            json_str: Contacts JSON document
+            config_path: Where saving methods write, or None for a
+                read-only manager
+
+        Returns:
+            ContactsManager instance
+
+        Raises:
+            ValueError: If json_str is not valid contacts JSON
+        """
+        try:
+            data = json.loads(json_str)
+            if isinstance(data, list):
+                data = {"contacts": data}
+            contacts = cls._parse_contacts(data)
+        except (KeyError, TypeError, AttributeError) as e:
+            raise ValueError(f"Invalid contacts JSON: {e}") from e
+
+        # Skip __init__ so no config file is read or created
+        manager = cls.__new__(cls)
+        manager.config_path = Path(config_path) if config_path is not None else None
+        manager.contacts = contacts
+        return manager
+
+    def to_json_string(self) -> str:
+        """
+        Serialize contacts in the contacts.json format.
+
+        The output round-trips through from_json_string.
+
+        Returns:
+            Indented JSON document: {"contacts": [...]}
+        """
+        return json.dumps({"contacts": [c.to_dict() for c in self.contacts]}, indent=2)
+
+    def _read_contacts(self) -> List[Contact]:
+        """Parse the contacts list from the configuration file."""
+        with open(self.config_path) as f:
+            data = json.load(f)
+
+        return self._parse_contacts(data)
+
+    @staticmethod
+    def _parse_contacts(data: Dict) -> List[Contact]:
+        """Build Contact objects from a contacts JSON document."""
+        contacts_data = data.get("contacts", [])
+        return [
+            Contact(
+                name=c["name"],
+                phone=c["phone"],
+                relationship_type=c.get("relationship_type", "other"),
+                notes=c.get("notes", ""),
+                added_at=c.get("added_at"),
+                aliases=c.get("aliases"),
+                email=c.get("email")
+            )
+            for c in contacts_data
+        ]
+
+    def reload(self):
+        """
+        Re-read contacts from the configuration file.
+
+        Intended for long-running processes where contacts.json may be
+        edited (e.g. by sync_contacts.py) while the process is running.
+        The new list is parsed in full before it replaces the in-memory
+        contacts, so a malformed file leaves the current contacts intact.
+
+        Raises:
+            FileNotFoundError: If the configuration file no longer exists
+            ValueError: If the file is not valid contacts JSON, or the
+                contacts did not come from a file
+        """
+        if self.config_path is None:
+            raise ValueError(f"Contacts were loaded from {CONTACTS_JSON_ENV}; there is no file to reload")
+
+        try:
+            contacts = self._read_contacts()
+        except (KeyError, TypeError, AttributeError) as e:
+            raise ValueError(f"Invalid contacts config {self.config_path}: {e}") from e
+
+        self.contacts = contacts
+        logger.info(f"Reloaded {len(self.contacts)} contacts from config")
+
     def _create_default_config(self):
         """Create default contacts configuration file."""
         default_config = {
+            "schema_version": CONTACTS_SCHEMA_VERSION,
             "_comment": "Manual contact configuration for Sprint 1",
             "_instructions": "Add your contacts here. Format: +1XXXXXXXXXX",
             "contacts": []
@@ -122,6 +367,12 @@ class ContactsManager:
                 logger.info(f"Found contact: {contact.name} -> {contact.phone}")
                 return contact
 
+        # Exact alias match (case-insensitive)
+        for contact in self.contacts:
+            if any(alias.lower() == name.lower() for alias in contact.aliases):
+                logger.info(f"Alias match: {name} -> {contact.name}")
+                return contact
+
         # Try partial match (contains)
         for contact in self.contacts:
             if name.lower() in contact.name.lower():
@@ -131,6 +382,37 @@ class ContactsManager:
         logger.warning(f"Contact not found: {name}")
         return None
 
+    def fuzzy_search_ranked(self, query: str, limit: int = 10) -> List[Tuple[float, Contact]]:
+        """
+        Rank contacts by how closely they match a partial name.
+
+        Meant for autocomplete and disambiguation: unlike get_contact_by_name,
+        which picks one contact, this returns every plausible candidate.
+        Each contact scores its best Jaro-Winkler similarity
+        (case-insensitive) against its full name, each word of the name,
+        and its aliases.
+
+        Args:
+            query: Name as typed so far, e.g. "jon"
+            limit: Maximum candidates to return
+
+        Returns:
+            List of (score, Contact) with score above 0.5, best first
+        """
+        query = query.lower().strip()
+        if not query:
+            return []
+
+        ranked = []
+        for contact in self.contacts:
+            names = [contact.name, *contact.name.split(), *contact.aliases]
+            score = max(jaro_winkler(query, n.lower()) for n in names)
+            if score > 0.5:
+                ranked.append((round(score, 3), contact))
+
+        ranked.sort(key=lambda pair: pair[0], reverse=True)
+        return ranked[:limit]
+
     def get_contact_by_phone(self, phone: str) -> Optional[Contact]:
         """
         Get contact by phone number.
@@ -146,6 +428,9 @@ class ContactsManager:
 
         for contact in self.contacts:
             normalized_contact = ''.join(c for c in contact.phone if c.isdigit())
+            if not normalized_search or not normalized_contact:
+                # Email handles have no digits and would match everything
+                continue
 
             # Match if search phone is suffix of contact phone
             # (handles +1 country code differences)
@@ -157,6 +442,33 @@ class ContactsManager:
         logger.warning(f"No contact found for phone: {phone}")
         return None
 
+    def get_contact_by_handle(self, handle: str) -> Optional[Contact]:
+        """
+        Get contact by a chat.db handle, which may be a phone or an email.
+
+        The handle is first compared case-insensitively with each contact's
+        phone and email strings, so email handles are recognized; phone
+        handles then fall back to get_contact_by_phone's digit matching.
+
+        Args:
+            handle: Handle ID as reported by the Messages database
+
+        Returns:
+            Contact object if found, None otherwise
+        """
+        wanted = handle.strip().lower()
+        if not wanted:
+            return None
+
+        for contact in self.contacts:
+            if contact.phone.strip().lower() == wanted or (
+                    contact.email and contact.email.strip().lower() == wanted):
+                return contact
+
+        if "@" in wanted:
+            return None
+        return self.get_contact_by_phone(handle)
+
     def list_contacts(self) -> List[Contact]:
         """
         Get all contacts.
@@ -166,6 +478,159 @@ class ContactsManager:
         """
         return self.contacts
 
+    def count(self) -> int:
+        """Get the number of contacts."""
+        return len(self.contacts)
+
+    def contacts_by_type(self, relationship_type: str) -> List[Contact]:
+        """
+        Get all contacts with a relationship type.
+
+        Args:
+            relationship_type: Type to match, case-insensitive (e.g. "family")
+
+        Returns:
+            List of matching Contact objects, in file order
+        """
+        wanted = relationship_type.strip().lower()
+        return [c for c in self.contacts if (c.relationship_type or "").lower() == wanted]
+
+    def rename_relationship_type(self, old: str, new: str) -> int:
+        """
+        Change a relationship type on every contact that has it, in memory.
+
+        Matches like contacts_by_type (case-insensitive), so renaming
+        "close_friend" also fixes "Close_Friend". Call save() to
+        persist the result.
+
+        Args:
+            old: Type to replace
+            new: Replacement type, stored exactly as given
+
+        Returns:
+            Number of contacts changed
+        """
+        changed = 0
+        for contact in self.contacts_by_type(old):
+            if contact.relationship_type != new:
+                contact.relationship_type = new
+                changed += 1
+
+        logger.info(f"Renamed relationship type '{old}' to '{new}' on {changed} contacts")
+        return changed
+
+    def search(self, criteria: SearchCriteria) -> List[Contact]:
+        """
+        Find contacts matching all of the given criteria.
+
+        Args:
+            criteria: Filters to apply; unset fields are ignored
+
+        Returns:
+            List of matching Contact objects, in file order
+        """
+        def strip_formatting(phone: str) -> str:
+            return phone.translate(str.maketrans('', '', ' ()-.'))
+
+        results = []
+        for contact in self.contacts:
+            if criteria.name_contains and criteria.name_contains.lower() not in contact.name.lower():
+                continue
+            if criteria.phone_prefix and not strip_formatting(contact.phone).startswith(
+                    strip_formatting(criteria.phone_prefix)):
+                continue
+            if criteria.relationship_type and (
+                    (contact.relationship_type or "").lower() != criteria.relationship_type.lower()):
+                continue
+            results.append(contact)
+
+        return results
+
+    def unique_relationship_types(self) -> List[str]:
+        """
+        Get every relationship type used in the contacts file.
+
+        Returns:
+            Sorted list of distinct relationship_type values
+        """
+        return sorted({c.relationship_type for c in self.contacts if c.relationship_type})
+
+    def contacts_added_recently(self, days: int) -> List[Contact]:
+        """
+        Get contacts added via add_contact in the last N days.
+
+        Contacts without an added_at timestamp (e.g. synced from macOS or
+        created before timestamps were recorded) are never included.
+
+        Args:
+            days: Look back this many days
+
+        Returns:
+            List of Contact objects, most recently added first
+        """
+        cutoff = datetime.now() - timedelta(days=days)
+
+        recent = []
+        for contact in self.contacts:
+            if not contact.added_at:
+                continue
+            try:
+                added = datetime.fromisoformat(contact.added_at)
+            except ValueError:
+                logger.warning(f"Invalid added_at for {contact.name}: {contact.added_at}")
+                continue
+            if added >= cutoff:
+                recent.append(contact)
+
+        recent.sort(key=lambda c: c.added_at, reverse=True)
+        return recent
+
+    def sorted_by_last_contact(self, messages_interface) -> List[Tuple[Contact, Optional[str]]]:
+        """
+        Get contacts ordered by when you last exchanged a message.
+
+        Runs one chat.db query per distinct phone number.
+
+        Args:
+            messages_interface: MessagesInterface providing last_messaged()
+
+        Returns:
+            List of (Contact, ISO timestamp or None) tuples, most recently
+            messaged first; contacts never messaged come last, in file order
+        """
+        last_dates: Dict[str, Optional[str]] = {}
+        for contact in self.contacts:
+            if contact.phone not in last_dates:
+                last_dates[contact.phone] = messages_interface.last_messaged(contact.phone)
+
+        results = [(c, last_dates[c.phone]) for c in self.contacts]
+        results.sort(key=lambda item: item[1] or "", reverse=True)
+        return results
+
+    def contacts_without_notes(self) -> List[Contact]:
+        """
+        Get contacts that have no notes.
+
+        Useful after importing from several sources, to find contacts
+        still missing relationship context.
+
+        Returns:
+            List of Contact objects whose notes are missing or blank
+        """
+        return [c for c in self.contacts if not (c.notes or "").strip()]
+
+    def contacts_with_email(self) -> List[Contact]:
+        """
+        Get contacts that have an email address.
+
+        Useful for checking that contacts reached through an Apple ID
+        email are present in the contacts file.
+
+        Returns:
+            List of Contact objects with a non-empty email
+        """
+        return [c for c in self.contacts if c.email]
+
     def add_contact(
         self,
         name: str,
@@ -188,8 +653,15 @@ class ContactsManager:
         Note:
             Sprint 1: Only updates JSON config
             Sprint 2: Will also update Life Planner database
+
+        Raises:
+            RuntimeError: If the contacts are read-only
         """
-        contact = Contact(name, phone, relationship_type, notes)
+        self._check_writable()
+        contact = Contact(
+            name, phone, relationship_type, notes,
+            added_at=datetime.now().isoformat(timespec="seconds")
+        )
         self.contacts.append(contact)
 
         # Save to config
@@ -198,8 +670,348 @@ class ContactsManager:
         logger.info(f"Added contact: {name}")
         return contact
 
+    def update_contact(self, name: str, update: ContactUpdate) -> bool:
+        """
+        Change fields of an existing contact and save the file.
+
+        Args:
+            name: Current contact name (exact match, case-insensitive)
+            update: Fields to change
+
+        Returns:
+            True if the contact was found and saved, False if not found
+
+        Raises:
+            RuntimeError: If the contacts are read-only
+        """
+        self._check_writable()
+        contact = next((c for c in self.contacts if c.name.lower() == name.lower()), None)
+        if not contact:
+            logger.warning(f"Contact not found for update: {name}")
+            return False
+
+        if update.new_name is not None:
+            contact.name = update.new_name
+        if update.new_phone is not None:
+            contact.phone = update.new_phone
+        if update.new_relationship_type is not None:
+            contact.relationship_type = update.new_relationship_type
+        if update.new_notes is not None:
+            contact.notes = update.new_notes
+        if update.add_alias and update.add_alias.lower() not in (a.lower() for a in contact.aliases):
+            contact.aliases.append(update.add_alias)
+
+        self._save_contacts()
+        logger.info(f"Updated contact: {name}")
+        return True
+
+    def normalize_all_phones(self) -> List[Dict[str, str]]:
+        """
+        Canonicalize every contact's phone number in memory.
+
+        Uses the same normalization as the macOS Contacts sync, written in
+        E.164 form ("+" then digits with country code, as apply_country_prefix
+        writes), so imported files with inconsistent formatting end up in
+        one format. Email handles are left as-is.
+        Call save() to persist the result.
+
+        Returns:
+            List of changes, each a dict with name, old_phone and new_phone
+        """
+        # Imported lazily: contacts_sync warns at import time when
+        # fuzzywuzzy is missing, which plain lookups don't need
+        from src.contacts_sync import normalize_phone_number
+
+        changes = []
+        for contact in self.contacts:
+            digits = normalize_phone_number(contact.phone)
+            normalized = f"+{digits}" if digits else ""
+            if not normalized or normalized == contact.phone:
+                continue
+
+            changes.append({
+                "name": contact.name,
+                "old_phone": contact.phone,
+                "new_phone": normalized
+            })
+            contact.phone = normalized
+
+        logger.info(f"Normalized {len(changes)} phone numbers")
+        return changes
+
+    def apply_country_prefix(self, default_country_code: str) -> List[Dict[str, str]]:
+        """
+        Add a country code to contacts saved in national format, in memory.
+
+        Numbers of national length (10 or 11 digits) with a single leading
+        trunk 0, e.g. UK "07911 123456", have the 0 replaced by the country
+        code; with code "1", 10-digit numbers get a 1 prepended. Numbers
+        dialled with an international prefix ("00" or "011") already carry
+        a country code and, like numbers starting with "+" and email
+        handles, are left as-is. Call save() to persist the result.
+
+        Args:
+            default_country_code: Calling code without "+", e.g. "44" or "1"
+
+        Returns:
+            List of changes, each a dict with name, old_phone and new_phone
+            (new numbers are "+" followed by digits, e.g. "+447911123456")
+
+        Raises:
+            ValueError: If default_country_code is not all digits
+        """
+        code = default_country_code.lstrip("+")
+        if not code.isdigit():
+            raise ValueError(f"Invalid country code: {default_country_code}")
+
+        changes = []
+        for contact in self.contacts:
+            phone = contact.phone.strip()
+            if "@" in phone or phone.startswith("+"):
+                continue
+
+            digits = "".join(c for c in phone if c.isdigit())
+            if digits.startswith(INTERNATIONAL_DIAL_PREFIXES):
+                continue
+            if digits.startswith("0") and len(digits) in NATIONAL_TRUNK_LENGTHS:
+                new_phone = f"+{code}{digits[1:]}"
+            elif code == "1" and len(digits) == 10:
+                new_phone = f"+1{digits}"
+            else:
+                continue
+
+            changes.append({
+                "name": contact.name,
+                "old_phone": contact.phone,
+                "new_phone": new_phone
+            })
+            contact.phone = new_phone
+
+        logger.info(f"Added country code +{code} to {len(changes)} phone numbers")
+        return changes
+
+    def import_from_applescript(self) -> List[Contact]:
+        """
+        Read contacts from macOS Contacts.app via AppleScript.
+
+        Lighter than the Contacts framework sync in contacts_sync, since it
+        needs no PyObjC. Contacts with several numbers use their mobile
+        number when one is labelled, otherwise the first listed. The
+        returned contacts are not added to this manager.
+
+        Returns:
+            List of Contact objects, one per person with a phone number
+
+        Raises:
+            RuntimeError: If osascript is unavailable or the script fails
+        """
+        try:
+            result = subprocess.run(
+                ['osascript', '-ss', '-e', CONTACTS_APPLESCRIPT],
+                capture_output=True,
+                text=True,
+                timeout=60
+            )
+        except FileNotFoundError:
+            raise RuntimeError("osascript not found - AppleScript import requires macOS")
+        except subprocess.TimeoutExpired:
+            raise RuntimeError("Timed out reading Contacts.app")
+
+        if result.returncode != 0:
+            raise RuntimeError(f"AppleScript failed: {result.stderr.strip()}")
+
+        contacts = self.parse_applescript_contacts(result.stdout)
+        logger.info(f"Read {len(contacts)} contacts from Contacts.app")
+        return contacts
+
+    @staticmethod
+    def export_to_applescript(contacts: List[Contact]) -> str:
+        """
+        Build an AppleScript that adds contacts to macOS Contacts.app.
+
+        The reverse of import_from_applescript. Contacts.app derives a
+        person's name from first and last name, so the first word becomes
+        the first name and the rest the last name. People whose name is
+        already in Contacts.app are skipped, so the script is safe to rerun.
+
+        Args:
+            contacts: Contacts to add
+
+        Returns:
+            AppleScript source that returns the number of people it created
+        """
+        lines = ['set added to 0', 'tell application "Contacts"']
+        for contact in contacts:
+            first, _, last = contact.name.strip().partition(" ")
+            name = escape_applescript_string(contact.name.strip())
+            phone = escape_applescript_string(contact.phone)
+            lines += [
+                f'    if (count of (people whose name is "{name}")) is 0 then',
+                f'        set p to make new person with properties '
+                f'{{first name:"{escape_applescript_string(first)}", '
+                f'last name:"{escape_applescript_string(last.strip())}"}}',
+                f'        make new phone at end of phones of p with properties '
+                f'{{label:"mobile", value:"{phone}"}}',
+                '        set added to added + 1',
+                '    end if',
+            ]
+        lines += ['    save', 'end tell', 'return added']
+        return "\n".join(lines) + "\n"
+
+    def export_to_macos(self, contacts: Optional[List[Contact]] = None) -> int:
+        """
+        Add contacts to macOS Contacts.app via AppleScript.
+
+        Args:
+            contacts: Contacts to add (defaults to all contacts)
+
+        Returns:
+            Number of people created (existing names are skipped)
+
+        Raises:
+            RuntimeError: If osascript is unavailable or the script fails
+        """
+        script = self.export_to_applescript(self.contacts if contacts is None else contacts)
+        try:
+            result = subprocess.run(
+                ['osascript', '-e', script],
+                capture_output=True,
+                text=True,
+                timeout=120
+            )
+        except FileNotFoundError:
+            raise RuntimeError("osascript not found - AppleScript export requires macOS")
+        except subprocess.TimeoutExpired:
+            raise RuntimeError("Timed out writing to Contacts.app")
+
+        if result.returncode != 0:
+            raise RuntimeError(f"AppleScript failed: {result.stderr.strip()}")
+
+        added = int(result.stdout.strip() or 0)
+        logger.info(f"Added {added} contacts to Contacts.app")
+        return added
+
+    @staticmethod
+    def parse_applescript_contacts(output: str) -> List[Contact]:
+        """
+        Parse the {name, label, number, ...} list from CONTACTS_APPLESCRIPT.
+
+        Args:
+            output: Raw osascript stdout
+
+        Returns:
+            List of Contact objects in first-seen order
+        """
+        phones: Dict[str, str] = {}
+        has_mobile = set()
+
+        items = applescript_list_to_list(output)
+        for i in range(0, len(items) - 2, 3):
+            name, label, number = (item.strip() for item in items[i:i + 3])
+            if not name or not number:
+                continue
+
+            is_mobile = "mobile" in label.lower()
+            if name not in phones or (is_mobile and name not in has_mobile):
+                phones[name] = number
+            if is_mobile:
+                has_mobile.add(name)
+
+        return [Contact(name=name, phone=phone) for name, phone in phones.items()]
+
+    @staticmethod
+    def import_from_google_contacts_csv(path: str) -> List[Contact]:
+        """
+        Read contacts from a Google Contacts CSV export.
+
+        Understands both export layouts: "Name" or "Given Name"/"Family
+        Name" (or "First Name"/"Last Name"), with "Phone N - Value" and
+        "Phone N - Type" (or "- Label") columns. Cells holding several
+        values separated by " ::: " are split.
+
+        Rows with the same name are combined. A contact's phone is its
+        first Mobile number, otherwise the first listed; any other
+        numbers are kept in notes. relationship_type comes from the
+        chosen phone's type via GOOGLE_PHONE_TYPES. The returned contacts
+        are not added to this manager.
+
+        Args:
+            path: Path to the exported CSV file
+
+        Returns:
+            List of Contact objects in first-seen order
+
+        Raises:
+            OSError: If the file can't be read
+            ValueError: If the file has no name or phone columns
+        """
+        with open(Path(path).expanduser(), newline='', encoding='utf-8-sig') as f:
+            reader = csv.DictReader(f)
+            fields = reader.fieldnames or []
+            phone_columns = [c for c in fields if c.startswith("Phone ") and c.endswith(" - Value")]
+            if not phone_columns or not ({"Name", "Given Name", "First Name"} & set(fields)):
+                raise ValueError(f"{path} is not a Google Contacts CSV export")
+
+            phones: Dict[str, List[Tuple[str, str]]] = {}
+            for row in reader:
+                name = (row.get("Name") or "").strip() or " ".join(
+                    (row.get(first) or "").strip()
+                    for first in ("Given Name", "First Name", "Family Name", "Last Name")
+                    if (row.get(first) or "").strip()
+                )
+                if not name:
+                    continue
+
+                for value_column in phone_columns:
+                    prefix = value_column[:-len(" - Value")]
+                    types = (row.get(f"{prefix} - Type") or row.get(f"{prefix} - Label") or "").split(" ::: ")
+                    for i, number in enumerate((row.get(value_column) or "").split(" ::: ")):
+                        number = number.strip()
+                        if number:
+                            # Starred types ("* Mobile") mark the primary number
+                            phone_type = (types[i] if i < len(types) else types[0]).strip(" *").lower()
+                            phones.setdefault(name, []).append((number, phone_type))
+
+        contacts = []
+        for name, numbers in phones.items():
+            primary = next((n for n in numbers if n[1] == "mobile"), numbers[0])
+            others = [number for number, _ in numbers if number != primary[0]]
+            contacts.append(Contact(
+                name=name,
+                phone=primary[0],
+                relationship_type=GOOGLE_PHONE_TYPES.get(primary[1], "other"),
+                notes=f"Other phones: {', '.join(others)}" if others else ""
+            ))
+
+        logger.info(f"Read {len(contacts)} contacts from {path}")
+        return contacts
+
+    def save(self):
+        """
+        Write the in-memory contacts back to the configuration file.
+
+        For persisting changes made by the in-memory mutators
+        (normalize_all_phones, apply_country_prefix, rename_relationship_type)
+        or by editing contacts directly. Other keys in the file are kept.
+
+        Raises:
+            RuntimeError: If the contacts are read-only
+        """
+        self._save_contacts()
+
+    @property
+    def read_only(self) -> bool:
+        """True when contacts were injected through IMESSAGE_CONTACTS_JSON."""
+        return self.config_path is None
+
+    def _check_writable(self):
+        """Raise if there is no contacts file to save to."""
+        if self.read_only:
+            raise RuntimeError(f"Contacts were loaded from {CONTACTS_JSON_ENV} and are read-only")
+
     def _save_contacts(self):
         """Save contacts back to configuration file."""
+        self._check_writable()
         try:
             with open(self.config_path) as f:
                 data = json.load(f)
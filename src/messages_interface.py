@@ -8,39 +8,33 @@ Sprint 1: Basic AppleScript sending
 Sprint 1.5: Message history reading with attributedBody parsing (macOS Ventura+)
 """
 
+import base64
+import csv
+import hashlib
+import json
 import subprocess
 import sqlite3
 import logging
+import math
 import plistlib
+import queue
+import random
 import re
+import statistics
+import string
+import threading
+import time
+from dataclasses import dataclass
+from enum import Enum
 from pathlib import Path
-from typing import Optional, List, Dict, Tuple
+from typing import Callable, Optional, List, Dict, Tuple, TextIO
 from datetime import datetime, timedelta
 
-logger = logging.getLogger(__name__)
-
-
-def escape_applescript_string(s: str) -> str:
-    r"""
-    Escape a string for safe use in AppleScript.
-
-    AppleScript strings use backslash escapes, so we must:
-    1. Escape backslashes first (\ -> \\)
-    2. Then escape double quotes (" -> \")
+from src.utils import (
+    cocoa_timestamp, escape_applescript_string, parse_timestamp_flexible, percentile, phone_country_code
+)
 
-    This prevents injection attacks where user data could break out of
-    the quoted string context in AppleScript commands.
-
-    Args:
-        s: The string to escape
-
-    Returns:
-        Escaped string safe for AppleScript double-quoted strings
-    """
-    if s is None:
-        return ""
-    # Escape backslashes first, then quotes
-    return s.replace('\\', '\\\\').replace('"', '\\"')
+logger = logging.getLogger(__name__)
 
 
 def is_group_chat_identifier(chat_identifier: Optional[str]) -> bool:
@@ -95,6 +89,70 @@ def sanitize_like_pattern(value: str) -> str:
     return value.replace('\\', '\\\\').replace('%', '\\%').replace('_', '\\_')
 
 
+def handle_like_pattern(phone: str) -> str:
+    """
+    Build the handle.id LIKE pattern that finds a contact's messages.
+
+    Every per-contact query matches handles through this, so a contact
+    saved as "(415) 555-1234" or "07911 123456" finds the handle stored
+    as "+14155551234" or "+447911123456". Phone numbers are matched on
+    their last 10 digits; emails and short codes as a substring.
+
+    The handle queries have no ESCAPE clause, so a backslash-escaped
+    pattern would never match an email like "first_last@icloud.com".
+    "%" is dropped instead, and "_" is left to match itself (or,
+    harmlessly, any one character).
+
+    Args:
+        phone: Phone number in any format, or an email handle
+
+    Returns:
+        Pattern for `h.id LIKE ?`
+
+    Example:
+        >>> handle_like_pattern("(415) 555-1234")
+        '%4155551234%'
+    """
+    digits = "".join(c for c in phone or "" if c.isdigit())
+    if len(digits) >= 7 and "@" not in phone:
+        return f"%{digits[-10:]}%"
+    return f"%{(phone or '').strip().replace('%', '')}%"
+
+
+def word_match(text: Optional[str], word: Optional[str]) -> bool:
+    """
+    Check whether text contains word as a whole word (case-insensitive).
+
+    SQLite LIKE has no word boundaries, so this is registered as the
+    WORD_MATCH SQL function for whole-word search. Punctuation around
+    words is ignored, so "meeting?" matches "meeting" but "meetings"
+    does not. A multi-word phrase matches the same words in a row.
+
+    Args:
+        text: Message text (None never matches)
+        word: Word or phrase to look for
+
+    Example:
+        >>> word_match("Meeting moved, ok?", "meeting")
+        True
+        >>> word_match("two meetings today", "meeting")
+        False
+        >>> word_match("lets have a team meeting today", "team meeting")
+        True
+    """
+    if not text or not word:
+        return False
+
+    def words(s: str) -> List[str]:
+        return [w for w in (w.strip(string.punctuation).lower() for w in s.split()) if w]
+
+    text_words, phrase = words(text), words(word)
+    if not phrase:
+        return False
+    n = len(phrase)
+    return any(text_words[i:i + n] == phrase for i in range(len(text_words) - n + 1))
+
+
 def parse_attributed_body(blob: bytes) -> Optional[str]:
     """
     Parse the attributedBody column from macOS Messages database.
@@ -266,9 +324,129 @@ def extract_text_from_blob(blob: bytes) -> Optional[str]:
     return None
 
 
+class OpenProgress(Enum):
+    """Phases reported by MessagesInterface.open_with_progress."""
+    CONNECTING = "connecting"
+    VALIDATING_SCHEMA = "validating_schema"
+    READY = "ready"
+
+
+class PaginationDirection(Enum):
+    """Which way a PaginationToken pages from its timestamp."""
+    OLDER = "older"
+    NEWER = "newer"
+
+
+@dataclass
+class PaginationToken:
+    """
+    Cursor for messages_for_phone_paged, passed to clients as an opaque string.
+
+    phone_hash ties the token to one conversation, so a token can't be
+    replayed against another contact.
+    """
+    phone_hash: str
+    last_cocoa_ts: int
+    direction: PaginationDirection
+
+    @staticmethod
+    def hash_phone(phone: str) -> str:
+        """Stable short hash of a handle (digits only when it has any)."""
+        digits = ''.join(c for c in phone if c.isdigit())
+        return hashlib.sha256((digits or phone.lower()).encode("utf-8")).hexdigest()[:16]
+
+
+def pagination_token_encode(token: PaginationToken) -> str:
+    """
+    Serialize a pagination token to a URL-safe string.
+
+    Returns:
+        Unpadded URL-safe base64 of the token's JSON
+    """
+    payload = json.dumps({
+        "p": token.phone_hash,
+        "t": token.last_cocoa_ts,
+        "d": token.direction.value
+    }, separators=(",", ":"))
+    return base64.urlsafe_b64encode(payload.encode("utf-8")).decode("ascii").rstrip("=")
+
+
+def pagination_token_decode(value: str) -> PaginationToken:
+    """
+    Parse a string produced by pagination_token_encode.
+
+    Raises:
+        ValueError: If the string is not a valid token
+    """
+    try:
+        padded = value + "=" * (-len(value) % 4)
+        data = json.loads(base64.urlsafe_b64decode(padded.encode("ascii")))
+        return PaginationToken(
+            phone_hash=str(data["p"]),
+            last_cocoa_ts=int(data["t"]),
+            direction=PaginationDirection(data["d"])
+        )
+    except (ValueError, TypeError, KeyError, UnicodeError) as e:
+        raise ValueError(f"Invalid pagination token: {value}") from e
+
+
+class AnalyticsBuilder:
+    """
+    Fluent configuration for conversation analytics.
+
+    Lets callers set only the options they care about, so new options can
+    be added without changing get_conversation_analytics' signature.
+    Unset options keep get_conversation_analytics' defaults.
+    """
+
+    def __init__(self):
+        self._phone: Optional[str] = None
+        self._days = 30
+        self._by_service = False
+        self._include_group_chats = True
+
+    def phone(self, phone: Optional[str]) -> "AnalyticsBuilder":
+        """Limit to one contact (None = all contacts)."""
+        self._phone = phone
+        return self
+
+    def days(self, days: int) -> "AnalyticsBuilder":
+        """Number of days to analyze."""
+        self._days = days
+        return self
+
+    def by_service(self, by_service: bool = True) -> "AnalyticsBuilder":
+        """Also split sent/received counts by iMessage vs SMS."""
+        self._by_service = by_service
+        return self
+
+    def include_group_chats(self, include: bool = True) -> "AnalyticsBuilder":
+        """Count group chat messages (default) or only one-to-one messages."""
+        self._include_group_chats = include
+        return self
+
+    def build_and_run(self, interface: "MessagesInterface") -> Dict:
+        """
+        Run the configured query.
+
+        Returns:
+            Dict: Analytics with the keys documented on
+                MessagesInterface.get_conversation_analytics
+        """
+        return interface._run_conversation_analytics(
+            phone=self._phone,
+            days=self._days,
+            by_service=self._by_service,
+            include_group_chats=self._include_group_chats
+        )
+
+
 class MessagesInterface:
     """Interface to macOS Messages app."""
 
+    # Tables every chat.db query relies on, checked by open_with_progress
+    REQUIRED_TABLES = ("message", "handle", "chat", "chat_message_join")
+
     def __init__(self, messages_db_path: str = "~/Library/Messages/chat.db"):
         """
         Initialize Messages interface.
@@ -279,6 +457,145 @@ class MessagesInterface:
         self.messages_db_path = Path(messages_db_path).expanduser()
         logger.info(f"Initialized MessagesInterface with DB: {self.messages_db_path}")
 
+    @classmethod
+    def open_with_progress(
+        cls,
+        messages_db_path: str = "~/Library/Messages/chat.db",
+        progress: Optional[Callable[[OpenProgress], None]] = None
+    ) -> "MessagesInterface":
+        """
+        Create an interface after checking the database opens, reporting each phase.
+
+        The plain constructor never touches chat.db, so problems only show
+        up on the first query. This opens the database up front, which on
+        multi-GB databases can take a moment, and calls `progress` as it goes.
+
+        Args:
+            messages_db_path: Path to Messages database (default: standard location)
+            progress: Called with each OpenProgress phase (default: log it)
+
+        Returns:
+            MessagesInterface for the validated database
+
+        Raises:
+            FileNotFoundError: If the database does not exist
+            sqlite3.Error: If it can't be opened or lacks REQUIRED_TABLES
+
+        Example:
+            mi = MessagesInterface.open_with_progress(
+                progress=lambda phase: print(phase.value, file=sys.stderr)
+            )
+        """
+        if progress is None:
+            def progress(phase: OpenProgress):
+                logger.info(f"Opening Messages database: {phase.value}")
+
+        interface = cls(messages_db_path)
+
+        progress(OpenProgress.CONNECTING)
+        if not interface.messages_db_path.exists():
+            raise FileNotFoundError(f"Messages database not found: {interface.messages_db_path}")
+        conn = sqlite3.connect(f"file:{interface.messages_db_path}?mode=ro", uri=True)
+
+        try:
+            progress(OpenProgress.VALIDATING_SCHEMA)
+            cursor = conn.cursor()
+            cursor.execute("SELECT name FROM sqlite_master WHERE type = 'table'")
+            tables = {row[0] for row in cursor.fetchall()}
+            missing = [t for t in cls.REQUIRED_TABLES if t not in tables]
+            if missing:
+                raise sqlite3.DatabaseError(f"Not a Messages database, missing tables: {', '.join(missing)}")
+        finally:
+            conn.close()
+
+        progress(OpenProgress.READY)
+        return interface
+
+    # File ID of Library/SMS/sms.db in an iOS backup: SHA-1 of "HomeDomain-Library/SMS/sms.db"
+    BACKUP_SMS_DB_FILE_ID = "3d0d7e5fb2ce288813306e4d4636395e047a3d28"
+
+    @classmethod
+    def find_backup_database(cls, backup_dir: str) -> Path:
+        """
+        Locate the messages database inside an iTunes/Finder or iCloud backup.
+
+        Accepts either one device's backup ({udid} directory) or the Backup
+        folder holding several, in which case the most recently modified
+        device backup that contains messages is used. The known hash path
+        is tried first, then Manifest.db is consulted. Encrypted backups
+        can't be read.
+
+        Args:
+            backup_dir: Backup directory, e.g.
+                ~/Library/Application Support/MobileSync/Backup/{udid}
+
+        Returns:
+            Path to the backed-up sms.db (same schema as chat.db)
+
+        Raises:
+            FileNotFoundError: If no messages database is found
+        """
+        root = Path(backup_dir).expanduser()
+        if not root.is_dir():
+            raise FileNotFoundError(f"Backup directory not found: {root}")
+
+        device_dirs = sorted((d for d in root.iterdir() if d.is_dir()),
+                             key=lambda d: d.stat().st_mtime, reverse=True)
+        for device_dir in [root] + device_dirs:
+            file_id = cls.BACKUP_SMS_DB_FILE_ID
+            manifest = device_dir / "Manifest.db"
+            if not (device_dir / file_id[:2] / file_id).exists() and manifest.exists():
+                try:
+                    conn = sqlite3.connect(f"file:{manifest}?mode=ro", uri=True)
+                    row = conn.execute(
+                        "SELECT fileID FROM Files WHERE domain = 'HomeDomain' "
+                        "AND relativePath = 'Library/SMS/sms.db'"
+                    ).fetchone()
+                    conn.close()
+                except sqlite3.Error as e:
+                    logger.warning(f"Could not read {manifest}: {e}")
+                    row = None
+                if row:
+                    file_id = row[0]
+
+            candidate = device_dir / file_id[:2] / file_id
+            if candidate.exists():
+                logger.info(f"Found backup messages database: {candidate}")
+                return candidate
+
+        raise FileNotFoundError(f"No messages database found in backup: {root}")
+
+    @classmethod
+    def open_backup(
+        cls,
+        backup_dir: str,
+        progress: Optional[Callable[[OpenProgress], None]] = None
+    ) -> "MessagesInterface":
+        """
+        Create an interface over the messages database in a device backup.
+
+        Locates the database with find_backup_database, then opens and
+        validates it read-only like open_with_progress. Useful for querying
+        history that has since been deleted from the Mac.
+
+        Args:
+            backup_dir: Device backup directory, or the Backup folder above it
+            progress: Called with each OpenProgress phase (default: log it)
+
+        Returns:
+            MessagesInterface for the backed-up database
+
+        Raises:
+            FileNotFoundError: If no messages database is found
+            sqlite3.Error: If it can't be opened or lacks REQUIRED_TABLES
+
+        Example:
+            mi = MessagesInterface.open_backup(
+                "~/Library/Application Support/MobileSync/Backup/00008030-001A"
+            )
+        """
+        return cls.open_with_progress(str(cls.find_backup_database(backup_dir)), progress=progress)
+
     def send_message(self, phone: str, message: str) -> dict:
         """
         Send an iMessage using AppleScript.
@@ -338,6 +655,109 @@ class MessagesInterface:
             logger.error(f"Exception sending message: {e}")
             return {"success": False, "error": str(e)}
 
+    def send_and_confirm(self, phone: str, message: str, timeout_secs: int = 10) -> dict:
+        """
+        Send a message and wait until chat.db shows it as delivered.
+
+        Slower than send_message, but tells you whether the message
+        actually reached the recipient's device. Delivery is read from the
+        outgoing message's is_delivered flag, polled once a second. The
+        message is identified as the newest outgoing row to the handle
+        added after the send, not by its text, since Ventura and later
+        often store sent text only in attributedBody.
+
+        Args:
+            phone: Phone number or iMessage handle (email)
+            message: Message text to send
+            timeout_secs: Give up waiting for delivery after this many seconds
+
+        Returns:
+            dict: {"sent": bool, "delivered": bool, "elapsed_secs": float,
+                "error": Optional[str]}
+        """
+        started = time.monotonic()
+
+        last_rowid = 0
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            last_rowid = conn.execute("SELECT COALESCE(MAX(ROWID), 0) FROM message").fetchone()[0]
+            conn.close()
+        except sqlite3.Error as e:
+            logger.warning(f"Could not read last message ROWID: {e}")
+
+        result = self.send_message(phone, message)
+        if not result.get("success"):
+            return {
+                "sent": False,
+                "delivered": False,
+                "elapsed_secs": round(time.monotonic() - started, 1),
+                "error": result.get("error")
+            }
+
+        delivered = False
+        while True:
+            try:
+                conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+                row = conn.execute("""
+                    SELECT m.is_delivered
+                    FROM message m
+                    JOIN handle h ON m.handle_id = h.ROWID
+                    WHERE h.id LIKE ?
+                        AND m.is_from_me = 1
+                        AND m.ROWID > ?
+                    ORDER BY m.ROWID DESC
+                    LIMIT 1
+                """, (handle_like_pattern(phone), last_rowid)).fetchone()
+                conn.close()
+                delivered = bool(row and row[0])
+            except sqlite3.Error as e:
+                logger.warning(f"Could not check delivery status: {e}")
+
+            elapsed = time.monotonic() - started
+            if delivered or elapsed >= timeout_secs:
+                break
+            time.sleep(1)
+
+        if delivered:
+            logger.info(f"Message to {phone} delivered after {elapsed:.1f}s")
+        else:
+            logger.warning(f"Message to {phone} not confirmed delivered within {timeout_secs}s")
+
+        return {
+            "sent": True,
+            "delivered": delivered,
+            "elapsed_secs": round(elapsed, 1),
+            "error": None
+        }
+
+    def send_message_with_followup_dismiss(
+        self,
+        phone: str,
+        message: str,
+        snooze_store,
+        snooze_days: int = 7
+    ) -> dict:
+        """
+        Send a message and snooze follow-ups for the recipient.
+
+        Replying usually resolves whatever follow-up was pending, so a
+        successful send records a temporary dismissal in the snooze store.
+
+        Args:
+            phone: Phone number or iMessage handle (email)
+            message: Message text to send
+            snooze_store: SnoozeStore to record the dismissal in
+            snooze_days: How long follow-ups stay hidden
+
+        Returns:
+            dict: {"success": bool, "error": Optional[str]}, plus
+                "snoozed_until" (ISO timestamp) on success
+        """
+        result = self.send_message(phone, message)
+        if result.get("success"):
+            result["snoozed_until"] = snooze_store.snooze(phone, days=snooze_days)
+        return result
+
     def get_recent_messages(
         self,
         phone: str,
@@ -397,7 +817,7 @@ class MessagesInterface:
             """
 
             # macOS Messages uses time since 2001-01-01 (Cocoa reference date)
-            cursor.execute(query, (f"%{phone}%", limit, offset))
+            cursor.execute(query, (handle_like_pattern(phone), limit, offset))
             rows = cursor.fetchall()
 
             messages = []
@@ -413,11 +833,7 @@ class MessagesInterface:
 
                 # Convert Cocoa timestamp to Python datetime
                 # Cocoa epoch: 2001-01-01 00:00:00 UTC
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
+                date = parse_timestamp_flexible(date_cocoa)
 
                 # Check if this is a group chat
                 is_group_chat = is_group_chat_identifier(cache_roomnames)
@@ -441,138 +857,472 @@ class MessagesInterface:
             logger.error(f"Error retrieving messages: {e}")
             return []
 
-    def check_permissions(self) -> dict:
-        """
-        Check if required permissions are granted.
-
-        Returns:
-            dict: {"messages_db_accessible": bool, "applescript_ready": bool}
-        """
-        permissions = {
-            "messages_db_accessible": self.messages_db_path.exists(),
-            "applescript_ready": True  # Will fail on first send if not granted
-        }
-
-        if not permissions["messages_db_accessible"]:
-            logger.warning(
-                "Messages database not accessible. "
-                "Grant Full Disk Access: System Settings → Privacy & Security"
-            )
-
-        return permissions
-
-    def get_all_recent_conversations(self, limit: int = 20) -> List[Dict]:
+    def messages_for_phone_paged(
+        self,
+        phone: str,
+        limit: int = 20,
+        token: Optional[PaginationToken] = None
+    ) -> Dict:
         """
-        Get recent messages from ALL conversations (not filtered by contact).
+        Page through a conversation with opaque tokens instead of offsets.
 
-        Sprint 2.5: Returns recent messages across all contacts, including
-        unknown numbers and people not in your contacts.
+        For stateless clients (HTTP, MCP): each page returns tokens for the
+        pages on either side, so clients never handle raw chat.db timestamps.
+        Unlike offsets, pages stay stable when new messages arrive.
 
         Args:
-            limit: Number of recent messages to retrieve
+            phone: Phone number or iMessage handle
+            limit: Messages per page
+            token: Token from a previous page (None = newest messages)
 
         Returns:
-            List[Dict]: List of message dicts with keys:
-                - text: Message content
-                - date: Timestamp
-                - is_from_me: Boolean (sent vs received)
-                - phone: Phone number or handle of sender/recipient
-                - contact_name: Contact name if available, otherwise phone/handle
+            Dict: Page with keys:
+                - messages: Messages, newest first, with the keys from
+                  get_recent_messages
+                - next_token: Encoded token for older messages (None at the start)
+                - prev_token: Encoded token for newer messages (None on the
+                  first page)
+
+        Raises:
+            ValueError: If the token was issued for a different conversation
 
         Example:
-            messages = interface.get_all_recent_conversations(limit=50)
+            page = interface.messages_for_phone_paged("+14155551234")
+            while page["next_token"]:
+                token = pagination_token_decode(page["next_token"])
+                page = interface.messages_for_phone_paged("+14155551234", token=token)
         """
-        logger.info(f"Retrieving {limit} most recent messages from all conversations")
+        logger.info(f"Retrieving paged messages for {phone}")
+
+        phone_hash = PaginationToken.hash_phone(phone)
+        if token and token.phone_hash != phone_hash:
+            raise ValueError("Pagination token belongs to a different conversation")
+
+        empty = {"messages": [], "next_token": None, "prev_token": None}
 
         if not self.messages_db_path.exists():
             logger.error(f"Messages database not found: {self.messages_db_path}")
-            return []
+            return empty
 
         try:
             conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
             cursor = conn.cursor()
 
-            # Query recent messages across all conversations
-            query = """
+            if token and token.direction == PaginationDirection.NEWER:
+                date_filter, order = "AND message.date > ?", "ASC"
+            elif token:
+                date_filter, order = "AND message.date < ?", "DESC"
+            else:
+                date_filter, order = "", "DESC"
+
+            # Fetch one extra row to know whether another page exists
+            cursor.execute(f"""
                 SELECT
                     message.text,
                     message.attributedBody,
                     message.date,
                     message.is_from_me,
-                    handle.id,
                     message.cache_roomnames
                 FROM message
-                LEFT JOIN handle ON message.handle_id = handle.ROWID
-                ORDER BY message.date DESC
+                JOIN handle ON message.handle_id = handle.ROWID
+                WHERE handle.id LIKE ?
+                    {date_filter}
+                ORDER BY message.date {order}
                 LIMIT ?
-            """
-
-            cursor.execute(query, (limit,))
+            """, (handle_like_pattern(phone),
+                  *([token.last_cocoa_ts] if token else []), limit + 1))
             rows = cursor.fetchall()
+            conn.close()
 
-            messages = []
-            for row in rows:
-                text, attributed_body, date_cocoa, is_from_me, handle_id, cache_roomnames = row
+            has_more = len(rows) > limit
+            rows = rows[:limit]
+            if order == "ASC":
+                rows.reverse()
 
-                # Extract message text
+            messages = []
+            for text, attributed_body, date_cocoa, is_from_me, cache_roomnames in rows:
                 message_text = text
                 if not message_text and attributed_body:
                     message_text = extract_text_from_blob(attributed_body)
 
-                # Convert timestamp
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
-
-                # Check if this is a group chat
+                date = parse_timestamp_flexible(date_cocoa)
                 is_group_chat = is_group_chat_identifier(cache_roomnames)
 
                 messages.append({
                     "text": message_text or "[message content not available]",
                     "date": date.isoformat() if date else None,
                     "is_from_me": bool(is_from_me),
-                    "phone": handle_id or "unknown",
-                    "contact_name": None,  # Will be populated by MCP tool if contact exists
                     "is_group_chat": is_group_chat,
-                    "group_id": cache_roomnames if is_group_chat else None,
-                    "sender_handle": handle_id  # For group chats, identifies who sent this message
+                    "group_id": cache_roomnames if is_group_chat else None
                 })
 
-            conn.close()
-            logger.info(f"Retrieved {len(messages)} messages from all conversations")
-            return messages
+            if not rows:
+                return empty
+
+            # Older pages exist if this page was cut short going back, or if
+            # we paged forward (the token's own page lies behind us)
+            paging_newer = bool(token) and token.direction == PaginationDirection.NEWER
+            has_older = has_more if not paging_newer else True
+            has_newer = bool(token) if not paging_newer else has_more
+
+            def encode(cocoa_ts: int, direction: PaginationDirection) -> str:
+                return pagination_token_encode(PaginationToken(phone_hash, cocoa_ts, direction))
+
+            return {
+                "messages": messages,
+                "next_token": encode(rows[-1][2], PaginationDirection.OLDER) if has_older else None,
+                "prev_token": encode(rows[0][2], PaginationDirection.NEWER) if has_newer else None
+            }
 
         except sqlite3.Error as e:
             logger.error(f"Database error: {e}")
-            return []
+            return empty
         except Exception as e:
-            logger.error(f"Error retrieving messages: {e}")
-            return []
+            logger.error(f"Error retrieving paged messages: {e}")
+            return empty
 
-    def get_messages_since(
-        self,
-        since: datetime,
-        limit: Optional[int] = None
-    ) -> List[Dict]:
+    def check_permissions(self) -> dict:
         """
-        Get messages modified since a specific timestamp.
-
-        Incremental indexing: Only fetch messages newer than the last
-        indexed timestamp to avoid re-processing unchanged data.
-
-        Args:
-            since: Return messages with date >= this timestamp
-            limit: Optional maximum number of messages to fetch
+        Check if required permissions are granted.
 
         Returns:
-            List[Dict]: List of message dicts sorted by date ascending
-                (oldest first, for chronological processing)
-
-        Example:
-            last_indexed = datetime(2025, 12, 31, 12, 0, 0)
-            new_messages = interface.get_messages_since(last_indexed)
+            dict: {"messages_db_accessible": bool, "applescript_ready": bool}
+        """
+        permissions = {
+            "messages_db_accessible": self.messages_db_path.exists(),
+            "applescript_ready": True  # Will fail on first send if not granted
+        }
+
+        if not permissions["messages_db_accessible"]:
+            logger.warning(
+                "Messages database not accessible. "
+                "Grant Full Disk Access: System Settings → Privacy & Security"
+            )
+
+        return permissions
+
+    def is_imessage_available(self) -> bool:
+        """
+        Check whether Messages.app has an enabled iMessage account.
+
+        Returns:
+            bool: True if AppleScript reports an enabled iMessage account,
+                False if not, or if Messages.app can't be scripted
+        """
+        script = '''
+        tell application "Messages"
+            return exists (1st account whose service type = iMessage and enabled is true)
+        end tell
+        '''
+
+        try:
+            result = subprocess.run(
+                ['osascript', '-e', script],
+                capture_output=True,
+                text=True,
+                timeout=10
+            )
+        except (OSError, subprocess.TimeoutExpired) as e:
+            logger.warning(f"Could not check iMessage availability: {e}")
+            return False
+
+        if result.returncode != 0:
+            logger.warning(f"iMessage availability check failed: {result.stderr.strip()}")
+            return False
+        return result.stdout.strip() == "true"
+
+    # Representative gateway queries checked by suggest_indices:
+    # (name, SQL, params, candidate index columns on message, rationale)
+    INDEX_PROBES = [
+        (
+            "messages_for_phone",
+            """
+                SELECT m.text FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ? ORDER BY m.date DESC LIMIT 20
+            """,
+            ("%0%",),
+            ["handle_id", "date"],
+            "Per-contact message lookups scan every message to find one handle"
+        ),
+        (
+            "analytics",
+            """
+                SELECT COUNT(*) FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE m.date >= ?
+            """,
+            (0,),
+            ["date"],
+            "Date-range analytics scan the whole message table"
+        ),
+        (
+            "recent_conversations",
+            "SELECT m.text FROM message m ORDER BY m.date DESC LIMIT 20",
+            (),
+            ["date"],
+            "Recent conversations sort every message by date"
+        ),
+    ]
+
+    def suggest_indices(self) -> List[Dict]:
+        """
+        Suggest SQLite indices that would speed up common gateway queries.
+
+        Runs EXPLAIN QUERY PLAN on representative queries and flags those
+        that scan the message table or sort it without an index.
+
+        Returns:
+            List[Dict]: One suggestion per missing index, with keys:
+                - table: Table to index
+                - columns: Columns for the index
+                - rationale: Why it helps, including the affected queries
+                - create_statement: CREATE INDEX statement to apply
+
+        Note:
+            chat.db is owned by Messages.app; apply suggestions to a copy,
+            or with Messages closed and a backup in place.
+        """
+        logger.info("Checking query plans for missing indices")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            suggestions: Dict[Tuple[str, ...], Dict] = {}
+            for name, sql, params, columns, rationale in self.INDEX_PROBES:
+                cursor.execute(f"EXPLAIN QUERY PLAN {sql}", params)
+                details = [row[-1] for row in cursor.fetchall()]
+
+                # SQLite reports "SCAN m" (3.36+) or "SCAN TABLE message AS m"
+                scans_message = any(
+                    re.match(r"SCAN (TABLE message|m)\b", d) and "INDEX" not in d
+                    for d in details
+                )
+                sorts_in_memory = any("USE TEMP B-TREE FOR ORDER BY" in d for d in details)
+                if not (scans_message or sorts_in_memory):
+                    continue
+
+                key = tuple(columns)
+                if key in suggestions:
+                    suggestions[key]["rationale"] += f"; {rationale.lower()} ({name})"
+                    continue
+
+                index_name = "idx_message_" + "_".join(columns)
+                suggestions[key] = {
+                    "table": "message",
+                    "columns": columns,
+                    "rationale": f"{rationale} ({name})",
+                    "create_statement": (
+                        f"CREATE INDEX IF NOT EXISTS {index_name} "
+                        f"ON message({', '.join(columns)});"
+                    )
+                }
+
+            conn.close()
+            logger.info(f"Found {len(suggestions)} index suggestions")
+            return list(suggestions.values())
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error checking query plans: {e}")
+            return []
+
+    def get_all_recent_conversations(self, limit: int = 20) -> List[Dict]:
+        """
+        Get recent messages from ALL conversations (not filtered by contact).
+
+        Sprint 2.5: Returns recent messages across all contacts, including
+        unknown numbers and people not in your contacts.
+
+        Args:
+            limit: Number of recent messages to retrieve
+
+        Returns:
+            List[Dict]: List of message dicts with keys:
+                - text: Message content
+                - date: Timestamp
+                - is_from_me: Boolean (sent vs received)
+                - phone: Phone number or handle of sender/recipient
+                - contact_name: Contact name if available, otherwise phone/handle
+
+        Example:
+            messages = interface.get_all_recent_conversations(limit=50)
+        """
+        logger.info(f"Retrieving {limit} most recent messages from all conversations")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            # Query recent messages across all conversations
+            query = """
+                SELECT
+                    message.text,
+                    message.attributedBody,
+                    message.date,
+                    message.is_from_me,
+                    handle.id,
+                    message.cache_roomnames
+                FROM message
+                LEFT JOIN handle ON message.handle_id = handle.ROWID
+                ORDER BY message.date DESC
+                LIMIT ?
+            """
+
+            cursor.execute(query, (limit,))
+            rows = cursor.fetchall()
+
+            messages = []
+            for row in rows:
+                text, attributed_body, date_cocoa, is_from_me, handle_id, cache_roomnames = row
+
+                # Extract message text
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                # Convert timestamp
+                date = parse_timestamp_flexible(date_cocoa)
+
+                # Check if this is a group chat
+                is_group_chat = is_group_chat_identifier(cache_roomnames)
+
+                messages.append({
+                    "text": message_text or "[message content not available]",
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "phone": handle_id or "unknown",
+                    "contact_name": None,  # Will be populated by MCP tool if contact exists
+                    "is_group_chat": is_group_chat,
+                    "group_id": cache_roomnames if is_group_chat else None,
+                    "sender_handle": handle_id  # For group chats, identifies who sent this message
+                })
+
+            conn.close()
+            logger.info(f"Retrieved {len(messages)} messages from all conversations")
+            return messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error retrieving messages: {e}")
+            return []
+
+    def conversations_matching(self, handle_pattern: str, limit: int = 20) -> List[Dict]:
+        """
+        Find conversations whose handle contains a pattern.
+
+        Works without a contacts file, e.g. "gmail.com" for Gmail handles
+        or "+44" for UK numbers.
+
+        Args:
+            handle_pattern: Substring to look for in handle IDs
+            limit: Maximum number of conversations
+
+        Returns:
+            List[Dict]: Conversations, most recently active first, with keys:
+                - phone: Phone number or handle
+                - message_count: Total messages with this handle
+                - last_message: Text of the latest message
+                - last_message_date: Timestamp of the latest message
+                - last_is_from_me: Whether you sent the latest message
+
+        Example:
+            uk = interface.conversations_matching("+44")
+        """
+        logger.info(f"Finding conversations with handles matching '{handle_pattern}'")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                WITH ranked AS (
+                    SELECT
+                        h.id AS handle,
+                        m.text,
+                        m.attributedBody,
+                        m.date,
+                        m.is_from_me,
+                        ROW_NUMBER() OVER (PARTITION BY h.id ORDER BY m.date DESC) AS rn,
+                        COUNT(*) OVER (PARTITION BY h.id) AS message_count
+                    FROM message m
+                    JOIN handle h ON m.handle_id = h.ROWID
+                    WHERE h.id LIKE ?
+                        AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                )
+                SELECT handle, text, attributedBody, date, is_from_me, message_count
+                FROM ranked
+                WHERE rn = 1
+                ORDER BY date DESC
+                LIMIT ?
+            """, (f"%{sanitize_like_pattern(handle_pattern)}%", limit))
+
+            conversations = []
+            for handle, text, attributed_body, date_cocoa, is_from_me, message_count in cursor.fetchall():
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                date = parse_timestamp_flexible(date_cocoa)
+
+                conversations.append({
+                    "phone": handle,
+                    "message_count": message_count,
+                    "last_message": message_text or "[message content not available]",
+                    "last_message_date": date.isoformat() if date else None,
+                    "last_is_from_me": bool(is_from_me)
+                })
+
+            conn.close()
+            logger.info(f"Found {len(conversations)} matching conversations")
+            return conversations
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error finding conversations: {e}")
+            return []
+
+    def get_messages_since(
+        self,
+        since: datetime,
+        limit: Optional[int] = None
+    ) -> List[Dict]:
+        """
+        Get messages modified since a specific timestamp.
+
+        Incremental indexing: Only fetch messages newer than the last
+        indexed timestamp to avoid re-processing unchanged data.
+
+        Args:
+            since: Return messages with date >= this timestamp
+            limit: Optional maximum number of messages to fetch
+
+        Returns:
+            List[Dict]: List of message dicts sorted by date ascending
+                (oldest first, for chronological processing)
+
+        Example:
+            last_indexed = datetime(2025, 12, 31, 12, 0, 0)
+            new_messages = interface.get_messages_since(last_indexed)
         """
         logger.info(f"Retrieving messages since {since.isoformat()}")
 
@@ -585,9 +1335,7 @@ class MessagesInterface:
             cursor = conn.cursor()
 
             # Convert datetime to Cocoa timestamp (nanoseconds since 2001-01-01)
-            cocoa_epoch = datetime(2001, 1, 1)
-            delta = since - cocoa_epoch
-            cocoa_timestamp = int(delta.total_seconds() * 1_000_000_000)
+            since_cocoa = cocoa_timestamp(since)
 
             # Query messages since timestamp
             # ORDER BY ASC for chronological processing
@@ -605,7 +1353,7 @@ class MessagesInterface:
                 ORDER BY message.date ASC
             """
 
-            params = [cocoa_timestamp]
+            params = [since_cocoa]
             if limit:
                 query += " LIMIT ?"
                 params.append(limit)
@@ -624,7 +1372,7 @@ class MessagesInterface:
 
                 # Convert timestamp
                 if date_cocoa:
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
+                    date = parse_timestamp_flexible(date_cocoa)
                 else:
                     date = None
 
@@ -653,11 +1401,111 @@ class MessagesInterface:
             logger.error(f"Error retrieving messages: {e}")
             return []
 
+    def watch(
+        self,
+        phone: Optional[str] = None,
+        interval: float = 2.0,
+        maxsize: int = 100,
+        stop: Optional[threading.Event] = None
+    ) -> "queue.Queue[Dict]":
+        """
+        Stream new messages to a queue from a background polling thread.
+
+        Only messages that arrive after the call are emitted, found by
+        polling for ROWIDs above the last one seen. The queue is bounded;
+        when the consumer falls behind, new messages are dropped with a
+        warning rather than blocking the poller.
+
+        Args:
+            phone: Only emit messages with this handle (None = all)
+            interval: Seconds between polls
+            maxsize: Queue capacity
+            stop: Set this event to end the polling thread
+
+        Returns:
+            queue.Queue yielding message dicts (text, date, is_from_me,
+            phone, is_group_chat, group_id) oldest first
+
+        Example:
+            stop = threading.Event()
+            incoming = interface.watch("+14155551234", stop=stop)
+            message = incoming.get()  # blocks until a message arrives
+            stop.set()
+        """
+        logger.info("Watching for new messages" + (f" from {phone}" if phone else "") +
+                    f" every {interval}s")
+
+        messages: "queue.Queue[Dict]" = queue.Queue(maxsize=maxsize)
+        stop = stop or threading.Event()
+
+        select = """
+            SELECT
+                m.ROWID,
+                m.text,
+                m.attributedBody,
+                m.date,
+                m.is_from_me,
+                h.id,
+                m.cache_roomnames
+            FROM message m
+            LEFT JOIN handle h ON m.handle_id = h.ROWID
+            WHERE m.ROWID > ?
+                AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+        """
+        params = []
+        if phone:
+            select += " AND h.id LIKE ?"
+            params.append(handle_like_pattern(phone))
+        select += " ORDER BY m.ROWID ASC"
+
+        def query(sql: str, args: List) -> List[Tuple]:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            try:
+                return conn.execute(sql, args).fetchall()
+            finally:
+                conn.close()
+
+        def poll():
+            last_rowid = None
+            while not stop.is_set():
+                try:
+                    if last_rowid is None:
+                        last_rowid = query("SELECT COALESCE(MAX(ROWID), 0) FROM message", [])[0][0]
+                    for row in query(select, [last_rowid] + params):
+                        rowid, text, attributed_body, date_cocoa, is_from_me, handle_id, cache_roomnames = row
+                        last_rowid = rowid
+
+                        message_text = text
+                        if not message_text and attributed_body:
+                            message_text = extract_text_from_blob(attributed_body)
+
+                        date = parse_timestamp_flexible(date_cocoa)
+                        is_group_chat = is_group_chat_identifier(cache_roomnames)
+
+                        try:
+                            messages.put_nowait({
+                                "text": message_text or "[message content not available]",
+                                "date": date.isoformat() if date else None,
+                                "is_from_me": bool(is_from_me),
+                                "phone": handle_id or "unknown",
+                                "is_group_chat": is_group_chat,
+                                "group_id": cache_roomnames if is_group_chat else None
+                            })
+                        except queue.Full:
+                            logger.warning(f"Watch queue full ({maxsize}), dropping message {rowid}")
+                except sqlite3.Error as e:
+                    logger.error(f"Database error: {e}")
+                stop.wait(interval)
+
+        threading.Thread(target=poll, name="messages-watch", daemon=True).start()
+        return messages
+
     def search_messages(
         self,
         query: str,
         phone: Optional[str] = None,
-        limit: int = 50
+        limit: int = 50,
+        whole_word: bool = False
     ) -> List[Dict]:
         """
         Search messages by content/keyword.
@@ -668,6 +1516,8 @@ class MessagesInterface:
             query: Search query (keyword or phrase)
             phone: Optional phone number to filter by specific contact
             limit: Maximum number of results
+            whole_word: Match query as whole words (see word_match), so
+                "meeting" doesn't match "meetings"
 
         Returns:
             List[Dict]: List of matching message dicts with keys:
@@ -693,8 +1543,16 @@ class MessagesInterface:
 
         try:
             conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            conn.create_function("WORD_MATCH", 2, word_match, deterministic=True)
             cursor = conn.cursor()
 
+            if whole_word:
+                text_condition = "WORD_MATCH(message.text, ?)"
+                text_param = query
+            else:
+                text_condition = "message.text LIKE ?"
+                text_param = f"%{query}%"
+
             # Build query based on whether we're filtering by phone
             if phone:
                 sql_query = """
@@ -707,12 +1565,12 @@ class MessagesInterface:
                         message.cache_roomnames
                     FROM message
                     JOIN handle ON message.handle_id = handle.ROWID
-                    WHERE (message.text LIKE ? OR message.attributedBody IS NOT NULL)
+                    WHERE ({text_condition} OR message.attributedBody IS NOT NULL)
                         AND handle.id LIKE ?
                     ORDER BY message.date DESC
                     LIMIT ?
-                """
-                cursor.execute(sql_query, (f"%{query}%", f"%{phone}%", limit))
+                """.format(text_condition=text_condition)
+                cursor.execute(sql_query, (text_param, handle_like_pattern(phone), limit))
             else:
                 sql_query = """
                     SELECT
@@ -724,11 +1582,11 @@ class MessagesInterface:
                         message.cache_roomnames
                     FROM message
                     LEFT JOIN handle ON message.handle_id = handle.ROWID
-                    WHERE message.text LIKE ? OR message.attributedBody IS NOT NULL
+                    WHERE {text_condition} OR message.attributedBody IS NOT NULL
                     ORDER BY message.date DESC
                     LIMIT ?
-                """
-                cursor.execute(sql_query, (f"%{query}%", limit))
+                """.format(text_condition=text_condition)
+                cursor.execute(sql_query, (text_param, limit))
 
             rows = cursor.fetchall()
 
@@ -746,15 +1604,14 @@ class MessagesInterface:
                     continue
 
                 # Check if query matches (for attributedBody messages)
-                if query.lower() not in message_text.lower():
+                if whole_word:
+                    if not word_match(message_text, query):
+                        continue
+                elif query.lower() not in message_text.lower():
                     continue
 
                 # Convert timestamp
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
+                date = parse_timestamp_flexible(date_cocoa)
 
                 # Create match snippet (show context around match)
                 match_snippet = self._create_snippet(message_text, query)
@@ -783,15 +1640,454 @@ class MessagesInterface:
             logger.error(f"Error searching messages: {e}")
             return []
 
-    def list_group_chats(self, limit: int = 50) -> List[Dict]:
+    def search_with_positions(self, phone: str, query: str, limit: int = 50) -> List[Dict]:
         """
-        List all group chats with participant information.
+        Search a conversation and report where the query occurs in each match.
 
-        Sprint 3: Discovers group conversations from the Messages database
-        by querying the chat table and joining with chat_handle_join for participants.
+        Lets clients (TUI, HTML) highlight matches without re-scanning the
+        text. Positions are character offsets into the returned text, found
+        case-insensitively and without overlaps, like the search itself.
 
         Args:
-            limit: Maximum number of group chats to return
+            phone: Phone number or iMessage handle
+            query: Search query (keyword or phrase)
+            limit: Maximum number of results
+
+        Returns:
+            List[Dict]: search_messages results, each with an extra
+                match_positions key: [[start, end], ...] with end exclusive
+
+        Example:
+            for m in interface.search_with_positions("+14155551234", "dinner"):
+                start, end = m["match_positions"][0]
+                print(m["text"][start:end])
+        """
+        results = self.search_messages(query=query, phone=phone, limit=limit)
+        if not query:
+            return results
+
+        pattern = re.compile(re.escape(query), re.IGNORECASE)
+        for result in results:
+            result["match_positions"] = [
+                [match.start(), match.end()] for match in pattern.finditer(result["text"])
+            ]
+        return results
+
+    def search_in_range(
+        self,
+        phone: str,
+        query: str,
+        start: datetime,
+        end: datetime,
+        limit: int = 50
+    ) -> List[Dict]:
+        """
+        Search a conversation for text within a date range.
+
+        Filters by date in SQL, so narrow ranges stay fast even in long
+        conversations.
+
+        Args:
+            phone: Phone number or iMessage handle
+            query: Text to search for (case-insensitive substring)
+            start: Earliest message time (inclusive)
+            end: Latest message time (inclusive)
+            limit: Maximum number of results
+
+        Returns:
+            List[Dict]: Matching messages, newest first, with the same keys
+                as search_messages
+
+        Example:
+            results = interface.search_in_range(
+                "+14155551234", "dinner", datetime(2026, 1, 1), datetime(2026, 2, 1)
+            )
+        """
+        logger.info(f"Searching messages with {phone} for '{query}' ({start} to {end})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            start_cocoa = cocoa_timestamp(start)
+            end_cocoa = cocoa_timestamp(end)
+
+            # attributedBody rows are matched after decoding (macOS Ventura+)
+            cursor.execute("""
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    h.id,
+                    m.cache_roomnames
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.text LIKE ? OR (m.text IS NULL AND m.attributedBody IS NOT NULL))
+                    AND m.date >= ?
+                    AND m.date <= ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date DESC
+            """, (handle_like_pattern(phone), f"%{query}%",
+                  start_cocoa, end_cocoa))
+
+            messages = []
+            for text, attributed_body, date_cocoa, is_from_me, handle_id, cache_roomnames in cursor:
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+                if not message_text or query.lower() not in message_text.lower():
+                    continue
+
+                date = parse_timestamp_flexible(date_cocoa)
+                is_group_chat = is_group_chat_identifier(cache_roomnames)
+
+                messages.append({
+                    "text": message_text,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "phone": handle_id or "unknown",
+                    "match_snippet": self._create_snippet(message_text, query),
+                    "is_group_chat": is_group_chat,
+                    "group_id": cache_roomnames if is_group_chat else None
+                })
+                if len(messages) >= limit:
+                    break
+
+            conn.close()
+            logger.info(f"Found {len(messages)} messages matching '{query}' in range")
+            return messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error searching messages in range: {e}")
+            return []
+
+    def search_in_groups(self, query: str, limit: int = 50) -> List[Dict]:
+        """
+        Search message text in group chats only.
+
+        Args:
+            query: Text to search for (case-insensitive substring)
+            limit: Maximum number of results
+
+        Returns:
+            List[Dict]: Matching messages, newest first, with the same keys
+                as search_messages plus:
+                - chat_display_name: The group's name (None if unnamed)
+
+        Example:
+            for m in interface.search_in_groups("brunch"):
+                print(f"[{m['chat_display_name'] or m['group_id']}] {m['text']}")
+        """
+        logger.info(f"Searching group chats for '{query}'")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            # chat.style 43 = group chat, 45 = one-to-one
+            cursor.execute("""
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    h.id,
+                    c.chat_identifier,
+                    c.display_name
+                FROM message m
+                JOIN chat_message_join cmj ON m.ROWID = cmj.message_id
+                JOIN chat c ON cmj.chat_id = c.ROWID
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE c.style = 43
+                    AND (m.text LIKE ? OR (m.text IS NULL AND m.attributedBody IS NOT NULL))
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date DESC
+            """, (f"%{query}%",))
+
+            messages = []
+            for text, attributed_body, date_cocoa, is_from_me, handle_id, chat_identifier, display_name in cursor:
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+                if not message_text or query.lower() not in message_text.lower():
+                    continue
+
+                date = parse_timestamp_flexible(date_cocoa)
+
+                messages.append({
+                    "text": message_text,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "phone": handle_id or "unknown",
+                    "match_snippet": self._create_snippet(message_text, query),
+                    "is_group_chat": True,
+                    "group_id": chat_identifier,
+                    "chat_display_name": display_name or None
+                })
+                if len(messages) >= limit:
+                    break
+
+            conn.close()
+            logger.info(f"Found {len(messages)} group messages matching '{query}'")
+            return messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error searching group messages: {e}")
+            return []
+
+    def search_with_proximity(
+        self,
+        phone: str,
+        terms: List[str],
+        max_distance: int = 5,
+        limit: int = 50
+    ) -> List[Dict]:
+        """
+        Find messages where all terms appear within a few words of each other.
+
+        chat.db has no full-text index, so the conversation's messages are
+        loaded into an in-memory SQLite FTS5 table and matched with
+        NEAR("term1" "term2", max_distance).
+
+        Args:
+            phone: Phone number or iMessage handle
+            terms: Words or phrases that must all appear
+            max_distance: Maximum number of words between the terms
+            limit: Maximum number of results
+
+        Returns:
+            List[Dict]: Matching messages, newest first, with keys:
+                - text: Message content
+                - date: Timestamp
+                - is_from_me: Boolean
+                - phone: Phone number or handle
+
+        Example:
+            # "meeting" within 5 words of "Tuesday"
+            results = interface.search_with_proximity("+14155551234", ["meeting", "Tuesday"])
+        """
+        logger.info(f"Proximity search for {terms} (distance: {max_distance}, contact: {phone})")
+
+        if not terms:
+            return []
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    h.id
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.text IS NOT NULL OR m.attributedBody IS NOT NULL)
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date DESC
+            """, (handle_like_pattern(phone),))
+            rows = cursor.fetchall()
+            conn.close()
+
+            candidates = []
+            for text, attributed_body, date_cocoa, is_from_me, handle_id in rows:
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+                if message_text:
+                    candidates.append((message_text, date_cocoa, is_from_me, handle_id))
+
+            # FTS5 strings are double-quoted; embedded quotes are doubled
+            quoted = " ".join('"' + term.replace('"', '""') + '"' for term in terms)
+            near_query = f"NEAR({quoted}, {max_distance})"
+
+            index = sqlite3.connect(":memory:")
+            index.execute("CREATE VIRTUAL TABLE message_fts USING fts5(text)")
+            index.executemany(
+                "INSERT INTO message_fts(rowid, text) VALUES (?, ?)",
+                ((i, c[0]) for i, c in enumerate(candidates))
+            )
+            matched = sorted(
+                row[0] for row in index.execute(
+                    "SELECT rowid FROM message_fts WHERE message_fts MATCH ?", (near_query,)
+                )
+            )
+            index.close()
+
+            messages = []
+            for i in matched[:limit]:
+                message_text, date_cocoa, is_from_me, handle_id = candidates[i]
+                date = parse_timestamp_flexible(date_cocoa)
+                messages.append({
+                    "text": message_text,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "phone": handle_id
+                })
+
+            logger.info(f"Found {len(messages)} messages matching {near_query}")
+            return messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error in proximity search: {e}")
+            return []
+
+    def contacts_mentioned_in_messages(
+        self,
+        phone: str,
+        contacts,
+        limit: int = 500
+    ) -> List[Dict]:
+        """
+        Find contacts whose names appear in a conversation.
+
+        Scans the most recent messages with a contact for each known
+        contact's full name or first name (whole words, case-insensitive).
+
+        Args:
+            phone: Phone number or handle of the conversation to scan
+            contacts: ContactsManager providing the names to look for
+            limit: Number of recent messages to scan
+
+        Returns:
+            List[Dict]: Mentioned contacts, most mentioned first:
+                - name: Contact name
+                - phone: Contact phone
+                - relationship_type: Contact relationship type
+                - mention_count: Number of mentions across scanned messages
+
+        Example:
+            mentions = interface.contacts_mentioned_in_messages("+14155551234", cm)
+            for m in mentions:
+                print(f"{m['name']} mentioned {m['mention_count']} times")
+        """
+        logger.info(f"Finding contacts mentioned in conversation with {phone}")
+
+        messages = self.get_recent_messages(phone=phone, limit=limit)
+        texts = [m["text"] for m in messages if m.get("text")]
+        if not texts:
+            return []
+
+        mentions = []
+        for contact in contacts.list_contacts():
+            full_name = contact.name.strip()
+            if not full_name:
+                continue
+
+            pattern = self._name_mention_pattern(full_name)
+            count = sum(len(pattern.findall(text)) for text in texts)
+            if count:
+                mentions.append({
+                    "name": contact.name,
+                    "phone": contact.phone,
+                    "relationship_type": contact.relationship_type,
+                    "mention_count": count
+                })
+
+        mentions.sort(key=lambda m: m["mention_count"], reverse=True)
+        logger.info(f"Found {len(mentions)} mentioned contacts")
+        return mentions
+
+    @staticmethod
+    def _name_mention_pattern(name: str) -> "re.Pattern":
+        """Match a contact's full or first name as whole words, case-insensitive."""
+        first_name = name.split()[0]
+        # Full name listed first so "John Doe" counts once, not twice
+        names = [name] if first_name == name else [name, first_name]
+        return re.compile(
+            r'\b(?:' + '|'.join(re.escape(n) for n in names) + r')\b',
+            re.IGNORECASE
+        )
+
+    def contact_pair_interaction(self, contact_a, contact_b, limit: int = 500) -> Dict:
+        """
+        Measure how often two contacts mention each other.
+
+        Scans messages received from each contact for the other's full or
+        first name. Mentions in both directions suggest the two know each
+        other - a building block for social graph features.
+
+        Args:
+            contact_a: First Contact (needs name and phone)
+            contact_b: Second Contact
+            limit: Number of recent messages to scan per conversation
+
+        Returns:
+            Dict: Pair interaction with keys:
+                - contact_a: Name of the first contact
+                - contact_b: Name of the second contact
+                - a_mentions_b: Times contact_a mentioned contact_b
+                - b_mentions_a: Times contact_b mentioned contact_a
+                - is_mutual: Whether both mentioned each other
+                - mutual_contact_name: "<contact_a> & <contact_b>" when the
+                  mentions are mutual, "" otherwise
+
+        Example:
+            pair = interface.contact_pair_interaction(john, jane)
+            if pair["is_mutual"]:
+                print(f"{pair['mutual_contact_name']} know each other")
+        """
+        logger.info(f"Analyzing mentions between {contact_a.name} and {contact_b.name}")
+
+        def count_mentions(sender, mentioned) -> int:
+            if not mentioned.name.strip():
+                return 0
+            pattern = self._name_mention_pattern(mentioned.name.strip())
+            messages = self.get_recent_messages(phone=sender.phone, limit=limit)
+            return sum(
+                len(pattern.findall(m["text"]))
+                for m in messages
+                if not m.get("is_from_me") and m.get("text")
+            )
+
+        a_mentions_b = count_mentions(contact_a, contact_b)
+        b_mentions_a = count_mentions(contact_b, contact_a)
+        is_mutual = a_mentions_b > 0 and b_mentions_a > 0
+
+        return {
+            "contact_a": contact_a.name,
+            "contact_b": contact_b.name,
+            "a_mentions_b": a_mentions_b,
+            "b_mentions_a": b_mentions_a,
+            "is_mutual": is_mutual,
+            "mutual_contact_name": f"{contact_a.name} & {contact_b.name}" if is_mutual else ""
+        }
+
+    def list_group_chats(self, limit: int = 50) -> List[Dict]:
+        """
+        List all group chats with participant information.
+
+        Sprint 3: Discovers group conversations from the Messages database
+        by querying the chat table and joining with chat_handle_join for participants.
+
+        Args:
+            limit: Maximum number of group chats to return
 
         Returns:
             List[Dict]: List of group chat dicts with keys:
@@ -856,11 +2152,7 @@ class MessagesInterface:
                     continue
 
                 # Convert Cocoa timestamp
-                if last_date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    last_date = cocoa_epoch + timedelta(seconds=last_date_cocoa / 1_000_000_000)
-                else:
-                    last_date = None
+                last_date = parse_timestamp_flexible(last_date_cocoa)
 
                 groups.append({
                     "group_id": chat_identifier,
@@ -905,6 +2197,7 @@ class MessagesInterface:
                 - date: Timestamp
                 - is_from_me: Boolean (sent vs received)
                 - sender_handle: Phone/email of message sender
+                - guid: Message GUID
                 - group_id: The group identifier
                 - display_name: The group name if set
                 - group_participants: List of all group participants
@@ -946,7 +2239,7 @@ class MessagesInterface:
                     JOIN handle h ON chj.handle_id = h.ROWID
                     WHERE h.id LIKE ?
                         AND (c.chat_identifier LIKE 'chat%' OR c.display_name IS NOT NULL)
-                """, (f"%{sanitize_like_pattern(participant_filter)}%",))
+                """, (handle_like_pattern(participant_filter),))
 
             chats = cursor.fetchall()
 
@@ -973,7 +2266,8 @@ class MessagesInterface:
                         m.attributedBody,
                         m.date,
                         m.is_from_me,
-                        h.id as sender_handle
+                        h.id as sender_handle,
+                        m.guid
                     FROM message m
                     JOIN chat_message_join cmj ON m.ROWID = cmj.message_id
                     LEFT JOIN handle h ON m.handle_id = h.ROWID
@@ -983,7 +2277,7 @@ class MessagesInterface:
                 """, (chat_rowid, limit))
 
                 for row in cursor.fetchall():
-                    text, attributed_body, date_cocoa, is_from_me, sender_handle = row
+                    text, attributed_body, date_cocoa, is_from_me, sender_handle, guid = row
 
                     # Extract message text
                     message_text = text
@@ -991,17 +2285,14 @@ class MessagesInterface:
                         message_text = extract_text_from_blob(attributed_body)
 
                     # Convert timestamp
-                    if date_cocoa:
-                        cocoa_epoch = datetime(2001, 1, 1)
-                        date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                    else:
-                        date = None
+                    date = parse_timestamp_flexible(date_cocoa)
 
                     messages.append({
                         "text": message_text or "[message content not available]",
                         "date": date.isoformat() if date else None,
                         "is_from_me": bool(is_from_me),
                         "sender_handle": sender_handle or "unknown",
+                        "guid": guid,
                         "group_id": chat_identifier,
                         "display_name": display_name,
                         "group_participants": participants
@@ -1082,6 +2373,16 @@ class MessagesInterface:
         3005: "remove_question",
     }
 
+    # Display emoji for added tapbacks
+    REACTION_EMOJI = {
+        "love": "❤️",
+        "like": "👍",
+        "dislike": "👎",
+        "laugh": "😂",
+        "emphasis": "‼️",
+        "question": "❓",
+    }
+
     def get_attachments(
         self,
         phone: Optional[str] = None,
@@ -1154,7 +2455,7 @@ class MessagesInterface:
 
             if phone:
                 query += " AND h.id LIKE ?"
-                params.append(f"%{sanitize_like_pattern(phone)}%")
+                params.append(handle_like_pattern(phone))
 
             if mime_type_filter:
                 query += " AND a.mime_type LIKE ?"
@@ -1175,13 +2476,11 @@ class MessagesInterface:
                 # Convert Cocoa timestamps
                 message_date = None
                 if message_date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    message_date = cocoa_epoch + timedelta(seconds=message_date_cocoa / 1_000_000_000)
+                    message_date = parse_timestamp_flexible(message_date_cocoa)
 
                 created_date = None
                 if created_date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    created_date = cocoa_epoch + timedelta(seconds=created_date_cocoa / 1_000_000_000)
+                    created_date = parse_timestamp_flexible(created_date_cocoa)
 
                 attachments.append({
                     "attachment_id": attachment_id,
@@ -1209,21 +2508,380 @@ class MessagesInterface:
             logger.error(f"Error getting attachments: {e}")
             return []
 
-    def get_unread_messages(self, limit: int = 50) -> List[Dict]:
+    def attachment_total_size(self, phone: Optional[str] = None) -> int:
         """
-        Get unread messages that are awaiting response.
+        Get the total size of attachments, optionally for a single contact.
 
-        T0 Feature: Surface messages that need attention.
+        T0 Feature: Report how much disk space conversations consume.
 
         Args:
-            limit: Maximum number of unread messages to return
+            phone: Optional phone number to filter by contact
 
         Returns:
-            List[Dict]: List of unread message dicts with keys:
-                - text: Message content
-                - date: Timestamp
-                - phone: Sender's phone/handle
-                - is_group_chat: Whether from a group
+            int: Sum of attachment total_bytes (0 if none or on error)
+
+        Example:
+            size = interface.attachment_total_size(phone="+14155551234")
+        """
+        logger.info(f"Getting attachment total size (phone: {phone})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return 0
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            query = """
+                SELECT SUM(a.total_bytes)
+                FROM attachment a
+                JOIN message_attachment_join maj ON a.ROWID = maj.attachment_id
+                JOIN message m ON maj.message_id = m.ROWID
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE 1=1
+            """
+            params = []
+
+            if phone:
+                query += " AND h.id LIKE ?"
+                params.append(handle_like_pattern(phone))
+
+            cursor.execute(query, params)
+            total = cursor.fetchone()[0] or 0
+
+            conn.close()
+            logger.info(f"Attachment total size: {total} bytes")
+            return total
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return 0
+        except Exception as e:
+            logger.error(f"Error getting attachment size: {e}")
+            return 0
+
+    def attachment_data_usage(self, phone: str) -> Dict:
+        """
+        Break down a conversation's attachment storage by direction and type.
+
+        Helps find which conversations fill ~/Library/Messages/Attachments.
+        Types are the MIME top-level type ("image", "video", ...), with
+        "other" for attachments that have none.
+
+        Args:
+            phone: Phone number or iMessage handle
+
+        Returns:
+            Dict: Usage with keys:
+                - total_bytes, sent_bytes, received_bytes
+                - by_type: [{"type", "count", "total_bytes"}], largest first
+
+        Example:
+            usage = interface.attachment_data_usage("+14155551234")
+            print(format_bytes(usage["received_bytes"]))
+        """
+        logger.info(f"Getting attachment data usage for {phone}")
+
+        usage = {"total_bytes": 0, "sent_bytes": 0, "received_bytes": 0, "by_type": []}
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return usage
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT
+                    m.is_from_me,
+                    COALESCE(NULLIF(SUBSTR(a.mime_type, 1, INSTR(a.mime_type, '/') - 1), ''), 'other'),
+                    COUNT(DISTINCT a.ROWID),
+                    SUM(COALESCE(a.total_bytes, 0))
+                FROM attachment a
+                JOIN message_attachment_join maj ON a.ROWID = maj.attachment_id
+                JOIN message m ON maj.message_id = m.ROWID
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                GROUP BY 1, 2
+            """, (handle_like_pattern(phone),))
+
+            by_type: Dict[str, Dict] = {}
+            for is_from_me, mime_group, count, total in cursor.fetchall():
+                total = total or 0
+                usage["sent_bytes" if is_from_me else "received_bytes"] += total
+                entry = by_type.setdefault(mime_group, {"type": mime_group, "count": 0, "total_bytes": 0})
+                entry["count"] += count
+                entry["total_bytes"] += total
+
+            conn.close()
+
+            usage["total_bytes"] = usage["sent_bytes"] + usage["received_bytes"]
+            usage["by_type"] = sorted(by_type.values(), key=lambda t: t["total_bytes"], reverse=True)
+            return usage
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return usage
+        except Exception as e:
+            logger.error(f"Error getting attachment data usage: {e}")
+            return usage
+
+    def unique_mime_types(self, phone: Optional[str] = None) -> List[str]:
+        """
+        List the distinct attachment MIME types, optionally for one contact.
+
+        T0 Feature: Discover which file types exist before filtering
+        get_attachments by mime_type_filter.
+
+        Args:
+            phone: Optional phone number to filter by contact
+
+        Returns:
+            List[str]: Sorted MIME types (attachments without one are skipped)
+
+        Example:
+            types = interface.unique_mime_types()  # ["image/heic", "video/quicktime", ...]
+        """
+        logger.info(f"Getting attachment MIME types (phone: {phone})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            query = """
+                SELECT DISTINCT a.mime_type
+                FROM attachment a
+                JOIN message_attachment_join maj ON a.ROWID = maj.attachment_id
+                JOIN message m ON maj.message_id = m.ROWID
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE a.mime_type IS NOT NULL AND a.mime_type != ''
+            """
+            params = []
+
+            if phone:
+                query += " AND h.id LIKE ?"
+                params.append(handle_like_pattern(phone))
+
+            query += " ORDER BY a.mime_type"
+
+            cursor.execute(query, params)
+            mime_types = [row[0] for row in cursor.fetchall()]
+
+            conn.close()
+            logger.info(f"Found {len(mime_types)} MIME types")
+            return mime_types
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting MIME types: {e}")
+            return []
+
+    def audio_message_count(self, phone: str) -> Dict:
+        """
+        Count audio attachments (voice memos) exchanged with a contact.
+
+        T0 Feature: Voice memo use is a strong intimacy signal, so it is
+        tracked separately from total attachment counts.
+
+        Args:
+            phone: Phone number or iMessage handle
+
+        Returns:
+            Dict: {"sent": int, "received": int} audio attachment counts
+
+        Example:
+            audio = interface.audio_message_count("+14155551234")
+            print(f"{audio['sent']} voice memos sent")
+        """
+        logger.info(f"Counting audio messages for {phone}")
+
+        stats = {"sent": 0, "received": 0}
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return stats
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT m.is_from_me, COUNT(DISTINCT a.ROWID)
+                FROM attachment a
+                JOIN message_attachment_join maj ON a.ROWID = maj.attachment_id
+                JOIN message m ON maj.message_id = m.ROWID
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE a.mime_type LIKE 'audio/%'
+                    AND h.id LIKE ?
+                GROUP BY m.is_from_me
+            """, (handle_like_pattern(phone),))
+
+            for is_from_me, count in cursor.fetchall():
+                stats["sent" if is_from_me else "received"] = count
+
+            conn.close()
+            return stats
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return stats
+        except Exception as e:
+            logger.error(f"Error counting audio messages: {e}")
+            return stats
+
+    def unread_thread_count(self) -> int:
+        """
+        Count conversations with at least one unread incoming message.
+
+        This is the number on the Messages.app dock badge, which is
+        usually more actionable than the raw unread message count.
+
+        Returns:
+            int: Distinct handles with unread messages (0 on error)
+        """
+        logger.info("Counting unread threads")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return 0
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT COUNT(DISTINCT handle_id)
+                FROM message
+                WHERE is_from_me = 0
+                    AND COALESCE(is_read, 0) = 0
+            """)
+            count = cursor.fetchone()[0] or 0
+
+            conn.close()
+            return count
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return 0
+        except Exception as e:
+            logger.error(f"Error counting unread threads: {e}")
+            return 0
+
+    def global_summary(self) -> Dict:
+        """
+        Collect at-a-glance counts across the whole Messages database.
+
+        Runs a handful of targeted queries plus follow-up detection over
+        the last 7 days, for a quick dashboard snapshot. Reactions are not
+        counted as messages.
+
+        Returns:
+            Dict: Summary with keys:
+                - total_messages: Messages in the database
+                - total_conversations: Chats in the database
+                - messages_today: Messages since local midnight
+                - messages_this_week: Messages since local midnight on Monday
+                - most_active_contact_handle: Handle with the most messages
+                  in the last 30 days (None if none)
+                - pending_followups: detect_follow_up_needed action items
+                - unread_thread_count: unread_thread_count result
+
+        Example:
+            summary = interface.global_summary()
+            print(f"{summary['messages_today']} messages today")
+        """
+        logger.info("Building global summary")
+
+        summary = {
+            "total_messages": 0,
+            "total_conversations": 0,
+            "messages_today": 0,
+            "messages_this_week": 0,
+            "most_active_contact_handle": None,
+            "pending_followups": 0,
+            "unread_thread_count": 0
+        }
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return summary
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            today = datetime.now().replace(hour=0, minute=0, second=0, microsecond=0)
+            week_start = today - timedelta(days=today.weekday())
+            month_ago = datetime.now() - timedelta(days=30)
+
+            not_reaction = "(associated_message_type IS NULL OR associated_message_type = 0)"
+
+            cursor.execute(f"""
+                SELECT
+                    COUNT(*),
+                    SUM(CASE WHEN date >= ? THEN 1 ELSE 0 END),
+                    SUM(CASE WHEN date >= ? THEN 1 ELSE 0 END)
+                FROM message
+                WHERE {not_reaction}
+            """, (cocoa_timestamp(today), cocoa_timestamp(week_start)))
+            total, today_count, week_count = cursor.fetchone()
+            summary["total_messages"] = total or 0
+            summary["messages_today"] = today_count or 0
+            summary["messages_this_week"] = week_count or 0
+
+            cursor.execute("SELECT COUNT(*) FROM chat")
+            summary["total_conversations"] = cursor.fetchone()[0] or 0
+
+            cursor.execute("""
+                SELECT h.id
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE m.date >= ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                GROUP BY h.id
+                ORDER BY COUNT(*) DESC
+                LIMIT 1
+            """, (cocoa_timestamp(month_ago),))
+            row = cursor.fetchone()
+            summary["most_active_contact_handle"] = row[0] if row else None
+
+            conn.close()
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return summary
+        except Exception as e:
+            logger.error(f"Error building global summary: {e}")
+            return summary
+
+        followups = self.detect_follow_up_needed(days=7)
+        summary["pending_followups"] = followups.get("summary", {}).get("total_action_items", 0)
+        summary["unread_thread_count"] = self.unread_thread_count()
+        return summary
+
+    def get_unread_messages(self, limit: int = 50) -> List[Dict]:
+        """
+        Get unread messages that are awaiting response.
+
+        T0 Feature: Surface messages that need attention.
+
+        Args:
+            limit: Maximum number of unread messages to return
+
+        Returns:
+            List[Dict]: List of unread message dicts with keys:
+                - text: Message content
+                - date: Timestamp
+                - phone: Sender's phone/handle
+                - is_group_chat: Whether from a group
                 - group_id: Group identifier if applicable
                 - days_old: How many days since the message
 
@@ -1280,8 +2938,7 @@ class MessagesInterface:
 
                 # Convert timestamp
                 if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
+                    date = parse_timestamp_flexible(date_cocoa)
                     days_old = (now - date).days
                 else:
                     date = None
@@ -1371,7 +3028,7 @@ class MessagesInterface:
 
             if phone:
                 query += " AND h.id LIKE ?"
-                params.append(f"%{sanitize_like_pattern(phone)}%")
+                params.append(handle_like_pattern(phone))
 
             query += " ORDER BY r.date DESC LIMIT ?"
             params.append(limit)
@@ -1396,11 +3053,7 @@ class MessagesInterface:
                     orig_preview = orig_preview[:100] + "..."
 
                 # Convert timestamp
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
+                date = parse_timestamp_flexible(date_cocoa)
 
                 reactions.append({
                     "reaction_type": reaction_type.replace("remove_", "") if is_removal else reaction_type,
@@ -1424,77 +3077,184 @@ class MessagesInterface:
             logger.error(f"Error getting reactions: {e}")
             return []
 
-    def get_conversation_analytics(
-        self,
-        phone: Optional[str] = None,
-        days: int = 30
-    ) -> Dict:
+    def reactions_for_message(self, message_guid: str) -> List[Dict]:
         """
-        Get analytics about message patterns and frequency.
+        List who reacted to a specific message.
 
-        T0 Feature: Understand communication patterns.
+        Tapbacks reference their target as "p:<part>/<GUID>" (or "bp:<GUID>"
+        for some attachments), so any of those forms match the given GUID.
+        Only added reactions (2000-2005) are returned, not removals.
 
         Args:
-            phone: Optional filter by specific contact (None = all contacts)
-            days: Number of days to analyze
+            message_guid: GUID of the message that was reacted to
 
         Returns:
-            Dict: Analytics including:
-                - total_messages: Total message count
-                - sent_count: Messages you sent
-                - received_count: Messages received
-                - avg_daily_messages: Average messages per day
-                - busiest_hour: Hour with most messages (0-23)
-                - busiest_day: Day of week with most messages
-                - top_contacts: Top 10 contacts by message volume
-                - response_stats: Average response time stats
-                - attachment_count: Number of attachments
-                - reaction_count: Number of reactions sent/received
+            List[Dict]: Reactions, oldest first, with keys:
+                - reactor_handle: Who reacted ("me" for your own reactions)
+                - reaction_type: love, like, dislike, laugh, emphasis, question
+                - date: When the reaction was added
 
         Example:
-            analytics = interface.get_conversation_analytics(days=30)
-            print(f"You exchanged {analytics['total_messages']} messages in the last 30 days")
+            for r in interface.reactions_for_message("ABCD-1234"):
+                print(f"{r['reactor_handle']}: {r['reaction_type']}")
         """
-        logger.info(f"Getting conversation analytics (phone: {phone}, days: {days})")
+        logger.info(f"Getting reactions for message {message_guid}")
 
         if not self.messages_db_path.exists():
             logger.error(f"Messages database not found: {self.messages_db_path}")
-            return {}
+            return []
 
         try:
             conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
             cursor = conn.cursor()
 
-            # Calculate date threshold
-            cutoff_date = datetime.now() - timedelta(days=days)
-            cocoa_epoch = datetime(2001, 1, 1)
-            cutoff_cocoa = int((cutoff_date - cocoa_epoch).total_seconds() * 1_000_000_000)
+            guid = sanitize_like_pattern(message_guid)
+            cursor.execute("""
+                SELECT
+                    r.associated_message_type,
+                    r.date,
+                    r.is_from_me,
+                    h.id
+                FROM message r
+                LEFT JOIN handle h ON r.handle_id = h.ROWID
+                WHERE (r.associated_message_guid = ?
+                        OR r.associated_message_guid LIKE ?
+                        OR r.associated_message_guid LIKE ?)
+                    AND r.associated_message_type BETWEEN 2000 AND 2005
+                ORDER BY r.date ASC
+            """, (message_guid, f"%/{guid}", f"%:{guid}"))
 
-            base_filter = "WHERE m.date >= ?"
-            params = [cutoff_cocoa]
+            reactions = []
+            for reaction_code, date_cocoa, is_from_me, reactor_handle in cursor.fetchall():
+                date = parse_timestamp_flexible(date_cocoa)
+                reactions.append({
+                    "reactor_handle": "me" if is_from_me else (reactor_handle or "unknown"),
+                    "reaction_type": self.REACTION_TYPES.get(reaction_code, f"unknown_{reaction_code}"),
+                    "date": date.isoformat() if date else None
+                })
 
-            if phone:
+            conn.close()
+            logger.info(f"Found {len(reactions)} reactions on {message_guid}")
+            return reactions
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting message reactions: {e}")
+            return []
+
+    @staticmethod
+    def analytics_builder() -> "AnalyticsBuilder":
+        """
+        Start configuring a conversation analytics query.
+
+        Example:
+            analytics = (MessagesInterface.analytics_builder()
+                         .phone("+14155551234").days(30).include_group_chats(False)
+                         .build_and_run(interface))
+        """
+        return AnalyticsBuilder()
+
+    def get_conversation_analytics(
+        self,
+        phone: Optional[str] = None,
+        days: int = 30,
+        by_service: bool = False
+    ) -> Dict:
+        """
+        Get analytics about message patterns and frequency.
+
+        T0 Feature: Understand communication patterns.
+
+        Args:
+            phone: Optional filter by specific contact (None = all contacts)
+            days: Number of days to analyze
+            by_service: Also split sent/received counts by service, adding
+                imessage_sent, imessage_received, sms_sent and sms_received
+
+        Returns:
+            Dict: Analytics including:
+                - total_messages: Total message count
+                - sent_count: Messages you sent
+                - received_count: Messages received
+                - avg_daily_messages: Average messages per day
+                - busiest_hour: Local hour with most messages (0-23)
+                - busiest_day: Day of week with most messages
+                - longest_conversation_day: {"date", "message_count"} for the
+                  single local date with the most messages (None if no messages)
+                - top_contacts: Top 10 contacts by message volume
+                - response_stats: Average response time stats
+                - attachment_count: Number of attachments
+                - reaction_count: Number of reactions sent/received
+
+        Example:
+            analytics = interface.get_conversation_analytics(days=30)
+            print(f"You exchanged {analytics['total_messages']} messages in the last 30 days")
+        """
+        return (self.analytics_builder()
+                .phone(phone)
+                .days(days)
+                .by_service(by_service)
+                .build_and_run(self))
+
+    def _run_conversation_analytics(
+        self,
+        phone: Optional[str],
+        days: int,
+        by_service: bool,
+        include_group_chats: bool
+    ) -> Dict:
+        """Run an analytics query configured by AnalyticsBuilder; see get_conversation_analytics."""
+        logger.info(f"Getting conversation analytics (phone: {phone}, days: {days}, "
+                    f"group chats: {include_group_chats})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return {}
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            # Calculate date threshold
+            cutoff_date = datetime.now() - timedelta(days=days)
+            cutoff_cocoa = cocoa_timestamp(cutoff_date)
+
+            base_filter = "WHERE m.date >= ?"
+            params = [cutoff_cocoa]
+
+            if phone:
                 base_filter += " AND h.id LIKE ?"
-                params.append(f"%{sanitize_like_pattern(phone)}%")
+                params.append(handle_like_pattern(phone))
+
+            if not include_group_chats:
+                base_filter += " AND (m.cache_roomnames IS NULL OR m.cache_roomnames = '')"
 
             # Get total counts
             cursor.execute(f"""
                 SELECT
                     COUNT(*) as total,
                     SUM(CASE WHEN m.is_from_me = 1 THEN 1 ELSE 0 END) as sent,
-                    SUM(CASE WHEN m.is_from_me = 0 THEN 1 ELSE 0 END) as received
+                    SUM(CASE WHEN m.is_from_me = 0 THEN 1 ELSE 0 END) as received,
+                    SUM(CASE WHEN m.is_from_me = 1 AND m.service = 'iMessage' THEN 1 ELSE 0 END) as imessage_sent,
+                    SUM(CASE WHEN m.is_from_me = 0 AND m.service = 'iMessage' THEN 1 ELSE 0 END) as imessage_received,
+                    SUM(CASE WHEN m.is_from_me = 1 AND m.service = 'SMS' THEN 1 ELSE 0 END) as sms_sent,
+                    SUM(CASE WHEN m.is_from_me = 0 AND m.service = 'SMS' THEN 1 ELSE 0 END) as sms_received
                 FROM message m
                 LEFT JOIN handle h ON m.handle_id = h.ROWID
                 {base_filter}
                 AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
             """, params)
             row = cursor.fetchone()
-            total, sent, received = row if row else (0, 0, 0)
+            total, sent, received, imessage_sent, imessage_received, sms_sent, sms_received = (
+                row if row else (0, 0, 0, 0, 0, 0, 0)
+            )
 
             # Get messages by hour (for busiest hour)
             cursor.execute(f"""
                 SELECT
-                    CAST((m.date / 1000000000 / 3600) % 24 AS INTEGER) as hour,
+                    CAST(strftime('%H', m.date / 1000000000 + 978307200, 'unixepoch', 'localtime') AS INTEGER) as hour,
                     COUNT(*) as count
                 FROM message m
                 LEFT JOIN handle h ON m.handle_id = h.ROWID
@@ -1509,7 +3269,7 @@ class MessagesInterface:
             # Get messages by day of week
             cursor.execute(f"""
                 SELECT
-                    CAST((m.date / 1000000000 / 86400 + 1) % 7 AS INTEGER) as dow,
+                    CAST(strftime('%w', m.date / 1000000000 + 978307200, 'unixepoch', 'localtime') AS INTEGER) as dow,
                     COUNT(*) as count
                 FROM message m
                 LEFT JOIN handle h ON m.handle_id = h.ROWID
@@ -1522,6 +3282,24 @@ class MessagesInterface:
             days_of_week = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"]
             busiest_day = days_of_week[dow_row[0]] if dow_row and dow_row[0] is not None else None
 
+            # Get the single most active date
+            cursor.execute(f"""
+                SELECT
+                    date(m.date / 1000000000 + 978307200, 'unixepoch', 'localtime') as day,
+                    COUNT(*) as count
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                {base_filter}
+                AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                GROUP BY day
+                ORDER BY count DESC, day DESC
+                LIMIT 1
+            """, params)
+            day_row = cursor.fetchone()
+            longest_conversation_day = (
+                {"date": day_row[0], "message_count": day_row[1]} if day_row else None
+            )
+
             # Get top contacts (only if not filtering by phone)
             top_contacts = []
             if not phone:
@@ -1569,12 +3347,21 @@ class MessagesInterface:
                 "avg_daily_messages": round((total or 0) / max(days, 1), 1),
                 "busiest_hour": busiest_hour,
                 "busiest_day": busiest_day,
+                "longest_conversation_day": longest_conversation_day,
                 "top_contacts": top_contacts,
                 "attachment_count": attachment_count,
                 "reaction_count": reaction_count,
                 "analysis_period_days": days
             }
 
+            if by_service:
+                analytics.update({
+                    "imessage_sent": imessage_sent or 0,
+                    "imessage_received": imessage_received or 0,
+                    "sms_sent": sms_sent or 0,
+                    "sms_received": sms_received or 0
+                })
+
             logger.info(f"Generated analytics: {total} messages over {days} days")
             return analytics
 
@@ -1585,200 +3372,1796 @@ class MessagesInterface:
             logger.error(f"Error getting analytics: {e}")
             return {}
 
-    # ===== T1 FEATURES =====
-
-    def get_message_thread(
+    def response_time_analytics(
         self,
-        message_guid: Optional[str] = None,
-        thread_originator_guid: Optional[str] = None,
-        limit: int = 50
-    ) -> List[Dict]:
+        phone: str,
+        days: int = 30,
+        percentiles: bool = False
+    ) -> Dict:
         """
-        Get messages in a reply thread.
+        Measure how quickly each side replies in a conversation.
 
-        T1 Feature: Follow reply chains and inline replies.
+        A response is the first message after one or more messages from
+        the other side; its response time is the gap since the last of
+        those messages. Reactions are ignored.
 
         Args:
-            message_guid: GUID of any message in the thread
-            thread_originator_guid: GUID of the thread starter (if known)
-            limit: Maximum messages to return
+            phone: Phone number or iMessage handle
+            days: Number of days to analyze
+            percentiles: Also include p50_seconds, p75_seconds and
+                p95_seconds (nearest-rank) for each side
 
         Returns:
-            List[Dict]: Messages in the thread, chronologically ordered:
-                - text: Message content
-                - date: Timestamp
-                - is_from_me: Boolean
-                - sender_handle: Who sent it
-                - is_thread_originator: Whether this started the thread
-                - reply_to_guid: What message this replies to
+            Dict: {"my_responses": {...}, "their_responses": {...}}, each with:
+                - count: Number of responses measured
+                - mean_seconds: Average response time (None if no samples)
+                - median_seconds: Median response time (None if no samples)
+                - p50_seconds, p75_seconds, p95_seconds: With percentiles=True
 
         Example:
-            thread = interface.get_message_thread(message_guid="...")
-            for msg in thread:
-                prefix = "📌 " if msg['is_thread_originator'] else "  └ "
-                print(f"{prefix}{msg['sender_handle']}: {msg['text'][:50]}")
+            stats = interface.response_time_analytics("+14155551234", percentiles=True)
+            print(f"You reply in {stats['my_responses']['median_seconds'] / 60:.0f} min")
         """
-        logger.info(f"Getting message thread (guid: {message_guid})")
-
-        if not message_guid and not thread_originator_guid:
-            logger.error("Either message_guid or thread_originator_guid required")
-            return []
+        logger.info(f"Getting response times for {phone} (days: {days})")
 
         if not self.messages_db_path.exists():
             logger.error(f"Messages database not found: {self.messages_db_path}")
-            return []
+            return {}
 
         try:
             conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
             cursor = conn.cursor()
 
-            # If we have message_guid but not thread_originator, find the originator
-            if message_guid and not thread_originator_guid:
-                cursor.execute("""
-                    SELECT thread_originator_guid, guid
-                    FROM message
-                    WHERE guid = ?
-                """, (message_guid,))
-                row = cursor.fetchone()
-                if row:
-                    thread_originator_guid = row[0] or row[1]
-
-            if not thread_originator_guid:
-                conn.close()
-                return []
+            cutoff_date = datetime.now() - timedelta(days=days)
+            cutoff_cocoa = cocoa_timestamp(cutoff_date)
 
-            # Get all messages in this thread
             cursor.execute("""
-                SELECT
-                    m.guid,
-                    m.text,
-                    m.attributedBody,
-                    m.date,
-                    m.is_from_me,
-                    h.id as sender_handle,
-                    m.thread_originator_guid,
-                    m.reply_to_guid
+                SELECT m.date, m.is_from_me
                 FROM message m
-                LEFT JOIN handle h ON m.handle_id = h.ROWID
-                WHERE m.guid = ?
-                   OR m.thread_originator_guid = ?
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND m.date >= ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
                 ORDER BY m.date ASC
-                LIMIT ?
-            """, (thread_originator_guid, thread_originator_guid, limit))
-
+            """, (handle_like_pattern(phone), cutoff_cocoa))
             rows = cursor.fetchall()
+            conn.close()
 
-            messages = []
-            for row in rows:
-                (guid, text, attributed_body, date_cocoa, is_from_me,
-                 sender_handle, orig_guid, reply_guid) = row
+            samples = {True: [], False: []}
+            for (prev_date, prev_from_me), (date, from_me) in zip(rows, rows[1:]):
+                if bool(from_me) != bool(prev_from_me):
+                    samples[bool(from_me)].append((date - prev_date) / 1_000_000_000)
+
+            def summarize(values: List[float]) -> Dict:
+                values = sorted(values)
+                stats = {
+                    "count": len(values),
+                    "mean_seconds": round(sum(values) / len(values), 1) if values else None,
+                    "median_seconds": round(statistics.median(values), 1) if values else None
+                }
+                if percentiles:
+                    for pct in (50, 75, 95):
+                        value = percentile(values, pct)
+                        stats[f"p{pct}_seconds"] = round(value, 1) if value is not None else None
+                return stats
 
-                # Extract text
-                message_text = text
-                if not message_text and attributed_body:
-                    message_text = extract_text_from_blob(attributed_body)
+            return {
+                "my_responses": summarize(samples[True]),
+                "their_responses": summarize(samples[False]),
+                "analysis_period_days": days
+            }
 
-                # Convert timestamp
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return {}
+        except Exception as e:
+            logger.error(f"Error getting response times: {e}")
+            return {}
 
-                messages.append({
-                    "guid": guid,
-                    "text": message_text or "[message content not available]",
-                    "date": date.isoformat() if date else None,
-                    "is_from_me": bool(is_from_me),
-                    "sender_handle": sender_handle or ("me" if is_from_me else "unknown"),
-                    "is_thread_originator": guid == thread_originator_guid,
-                    "reply_to_guid": reply_guid
-                })
+    # Period units accepted by the *_timeline / *_trend methods
+    PERIOD_UNITS = ("daily", "weekly", "monthly")
 
-            conn.close()
-            logger.info(f"Found {len(messages)} messages in thread")
-            return messages
+    @staticmethod
+    def _period_windows(period: str, periods: int) -> List[Tuple[str, datetime, datetime]]:
+        """
+        Build consecutive calendar periods ending with the current one.
+
+        Periods follow the local calendar, starting at local midnight, and
+        are compared against chat.db through cocoa_timestamp. Weeks start
+        on Monday. Labels are "2026-01-15" (daily),
+        "2026-W03" (weekly, ISO week) and "2026-01" (monthly).
+
+        Returns:
+            List of (label, start, end) tuples, oldest first; end is exclusive
+        """
+        today = datetime.now().replace(hour=0, minute=0, second=0, microsecond=0)
+
+        if period == "daily":
+            starts = [today - timedelta(days=i) for i in range(periods)]
+        elif period == "weekly":
+            this_week = today - timedelta(days=today.weekday())
+            starts = [this_week - timedelta(weeks=i) for i in range(periods)]
+        elif period == "monthly":
+            starts = []
+            year, month = today.year, today.month
+            for _ in range(periods):
+                starts.append(datetime(year, month, 1))
+                year, month = (year, month - 1) if month > 1 else (year - 1, 12)
+        else:
+            raise ValueError(f"Unknown period: {period} (expected one of {MessagesInterface.PERIOD_UNITS})")
+
+        windows = []
+        for start in reversed(starts):
+            if period == "daily":
+                end = start + timedelta(days=1)
+                label = start.date().isoformat()
+            elif period == "weekly":
+                end = start + timedelta(weeks=1)
+                iso_year, iso_week, _ = start.isocalendar()
+                label = f"{iso_year}-W{iso_week:02d}"
+            else:
+                end = datetime(start.year + start.month // 12, start.month % 12 + 1, 1)
+                label = start.strftime("%Y-%m")
+            windows.append((label, start, end))
+        return windows
+
+    def stats_timeline(self, phone: str, period: str = "weekly", periods: int = 12) -> List[Dict]:
+        """
+        Track conversation volume and responsiveness over time.
+
+        Produces one entry per calendar period, for charting how a
+        conversation's health changes. Reactions are ignored.
+
+        Args:
+            phone: Phone number or iMessage handle
+            period: "daily", "weekly" or "monthly"
+            periods: Number of periods, ending with the current one
+
+        Returns:
+            List[Dict]: One entry per period, oldest first, with keys:
+                - label: Period label, e.g. "2026-W03" or "2026-01"
+                - sent: Messages you sent
+                - received: Messages received
+                - response_time_median: Median seconds between a message and
+                  the other side's reply, either direction (None if no replies)
+
+        Example:
+            for p in interface.stats_timeline("+14155551234", "monthly", 6):
+                print(f"{p['label']}: {p['sent'] + p['received']} messages")
+        """
+        logger.info(f"Getting {period} stats timeline for {phone} ({periods} periods)")
+
+        windows = self._period_windows(period, periods)
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            activity = self._window_activity(phone, [(start, end) for _, start, end in windows])
+            return [
+                {
+                    "label": label,
+                    "sent": sent,
+                    "received": received,
+                    "response_time_median": round(statistics.median(samples), 1) if samples else None
+                }
+                for (label, _, _), (sent, received, samples) in zip(windows, activity)
+            ]
 
         except sqlite3.Error as e:
             logger.error(f"Database error: {e}")
             return []
         except Exception as e:
-            logger.error(f"Error getting thread: {e}")
+            logger.error(f"Error getting stats timeline: {e}")
             return []
 
-    def extract_links(
-        self,
-        phone: Optional[str] = None,
-        days: Optional[int] = None,
-        limit: int = 100
-    ) -> List[Dict]:
+    def handle_stats_timeline(self, phone: str, bucket_days: int = 7, buckets: int = 12) -> List[Dict]:
         """
-        Extract URLs shared in conversations.
+        Track a conversation over rolling fixed-length windows ending now.
 
-        T1 Feature: Find all links that have been shared.
+        Unlike stats_timeline, windows are counted back from the current
+        moment rather than aligned to calendar periods, so the newest
+        bucket is always complete. Raw data for a relationship trajectory
+        chart. Reactions are ignored.
 
         Args:
-            phone: Optional filter by contact
-            days: Optional filter by recency
-            limit: Maximum links to return
+            phone: Phone number or iMessage handle
+            bucket_days: Length of each window in days
+            buckets: Number of windows
 
         Returns:
-            List[Dict]: Link information including:
-                - url: The extracted URL
-                - message_text: Context from the message
-                - date: When shared
-                - is_from_me: Whether you shared it
-                - sender_handle: Who shared it
+            List[Dict]: One entry per window, newest first, with keys:
+                - start_date: ISO timestamp the window starts at (inclusive)
+                - end_date: ISO timestamp the window ends at (exclusive)
+                - sent: Messages you sent
+                - received: Messages received
+                - response_time_p50: Median reply time in seconds, either
+                  direction (None if no replies)
 
         Example:
-            links = interface.extract_links(days=7)
-            for link in links:
-                print(f"{link['sender_handle']} shared: {link['url']}")
+            for b in interface.handle_stats_timeline("+14155551234", 30, 6):
+                print(f"{b['start_date'][:10]}: {b['sent'] + b['received']}")
         """
-        logger.info(f"Extracting links (phone: {phone}, days: {days})")
+        logger.info(f"Getting {buckets} x {bucket_days}-day stats for {phone}")
+
+        now = datetime.now()
+        windows = [
+            (now - timedelta(days=bucket_days * (i + 1)), now - timedelta(days=bucket_days * i))
+            for i in reversed(range(buckets))
+        ]
 
         if not self.messages_db_path.exists():
             logger.error(f"Messages database not found: {self.messages_db_path}")
             return []
 
         try:
-            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
-            cursor = conn.cursor()
+            activity = self._window_activity(phone, windows)
+            timeline = [
+                {
+                    "start_date": start.isoformat(),
+                    "end_date": end.isoformat(),
+                    "sent": sent,
+                    "received": received,
+                    "response_time_p50": round(statistics.median(samples), 1) if samples else None
+                }
+                for (start, end), (sent, received, samples) in zip(windows, activity)
+            ]
+            timeline.reverse()
+            return timeline
 
-            # URL regex pattern
-            url_pattern = re.compile(
-                r'https?://[^\s<>"{}|\\^`\[\]]+'
-            )
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting handle stats timeline: {e}")
+            return []
 
-            cocoa_epoch = datetime(2001, 1, 1)
-            cutoff_cocoa = None
-            if days:
-                cutoff_date = datetime.now() - timedelta(days=days)
-                cutoff_cocoa = int((cutoff_date - cocoa_epoch).total_seconds() * 1_000_000_000)
+    def _window_activity(
+        self,
+        phone: str,
+        windows: List[Tuple[datetime, datetime]]
+    ) -> List[Tuple[int, int, List[float]]]:
+        """
+        Count messages and reply times per window for the timeline methods.
 
-            filters = []
-            params_base = []
-            if phone:
-                filters.append("h.id LIKE ?")
-                params_base.append(f"%{sanitize_like_pattern(phone)}%")
-            if cutoff_cocoa is not None:
-                filters.append("m.date >= ?")
-                params_base.append(cutoff_cocoa)
-            filter_sql = (" AND " + " AND ".join(filters)) if filters else ""
+        Args:
+            phone: Phone number or iMessage handle
+            windows: Consecutive (start, end) pairs, oldest first; end is exclusive
 
-            links: List[Dict] = []
+        Returns:
+            (sent, received, reply_seconds) per window. A reply is
+            attributed to the window it was sent in.
+
+        Raises:
+            sqlite3.Error: If the database can't be read
+        """
+        conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+        try:
+            cursor = conn.cursor()
+
+            start_cocoa = cocoa_timestamp(windows[0][0])
+            end_cocoa = cocoa_timestamp(windows[-1][1])
+
+            cursor.execute("""
+                SELECT m.date, m.is_from_me
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND m.date >= ?
+                    AND m.date < ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date ASC
+            """, (handle_like_pattern(phone), start_cocoa, end_cocoa))
+            rows = cursor.fetchall()
+        finally:
+            conn.close()
+
+        sent = [0] * len(windows)
+        received = [0] * len(windows)
+        response_times: List[List[float]] = [[] for _ in windows]
+        previous = None
+        index = 0
+        for date_cocoa, is_from_me in rows:
+            date = parse_timestamp_flexible(date_cocoa)
+            while index < len(windows) - 1 and date >= windows[index][1]:
+                index += 1
+
+            if is_from_me:
+                sent[index] += 1
+            else:
+                received[index] += 1
+            if previous and bool(previous[1]) != bool(is_from_me):
+                response_times[index].append((date_cocoa - previous[0]) / 1_000_000_000)
+            previous = (date_cocoa, is_from_me)
+
+        return list(zip(sent, received, response_times))
+
+    # Emoji used by emoji_sentiment_ratio. Variation selectors are left off
+    # so "❤" matches both the text and emoji presentation of a heart.
+    POSITIVE_EMOJI = (
+        "😊", "😀", "😃", "😄", "😁", "😆", "😂", "🤣", "🙂", "😍", "🥰", "😘",
+        "❤", "💕", "💖", "💗", "💙", "💚", "💛", "💜", "🧡", "👍", "🙌", "👏",
+        "🎉", "🥳", "✨", "😎", "🤗", "💯",
+    )
+    NEGATIVE_EMOJI = (
+        "😢", "😭", "😞", "😔", "😟", "😕", "🙁", "☹", "😠", "😡", "🤬", "😤",
+        "💔", "👎", "😩", "😫", "😖", "😣", "😒", "🙄", "😱", "😨", "😰",
+    )
+
+    def emoji_sentiment_ratio(self, phone: str, days: Optional[int] = None) -> Dict:
+        """
+        Estimate a conversation's mood from the emoji it uses.
+
+        A quick heuristic that needs no NLP: messages containing any
+        POSITIVE_EMOJI count as positive, any NEGATIVE_EMOJI as negative
+        (a message can be both), and messages with neither as neutral.
+        Reactions are ignored.
+
+        Args:
+            phone: Phone number or iMessage handle
+            days: Optional limit to last N days (None = all time)
+
+        Returns:
+            Dict: Sentiment counts with keys:
+                - positive_messages: Messages with a positive emoji
+                - negative_messages: Messages with a negative emoji
+                - neutral_messages: Messages with neither
+                - positive_ratio: positive / (positive + negative), rounded
+                  to 3 places (None when no message has either)
+
+        Example:
+            mood = interface.emoji_sentiment_ratio("+14155551234", days=30)
+            print(f"{mood['positive_ratio']:.0%} positive")
+        """
+        logger.info(f"Getting emoji sentiment for {phone} (days: {days})")
+
+        empty = {
+            "positive_messages": 0,
+            "negative_messages": 0,
+            "neutral_messages": 0,
+            "positive_ratio": None
+        }
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return empty
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            query = """
+                SELECT m.text, m.attributedBody
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+            """
+            params = [handle_like_pattern(phone)]
+
+            if days:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                cutoff_cocoa = cocoa_timestamp(cutoff_date)
+                query += " AND m.date >= ?"
+                params.append(cutoff_cocoa)
+
+            cursor.execute(query, params)
+            texts = []
+            for text, attributed_body in cursor:
+                if not text and attributed_body:
+                    text = extract_text_from_blob(attributed_body)
+                texts.append(text or "")
+            conn.close()
+
+            return self._emoji_sentiment(texts)
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return empty
+        except Exception as e:
+            logger.error(f"Error getting emoji sentiment: {e}")
+            return empty
+
+    @classmethod
+    def _emoji_sentiment(cls, texts: List[str]) -> Dict:
+        """Classify message texts by emoji; see emoji_sentiment_ratio for the keys."""
+        positive = negative = neutral = 0
+        for text in texts:
+            is_positive = any(e in text for e in cls.POSITIVE_EMOJI)
+            is_negative = any(e in text for e in cls.NEGATIVE_EMOJI)
+            positive += is_positive
+            negative += is_negative
+            neutral += not (is_positive or is_negative)
+
+        with_emoji = positive + negative
+        return {
+            "positive_messages": positive,
+            "negative_messages": negative,
+            "neutral_messages": neutral,
+            "positive_ratio": round(positive / with_emoji, 3) if with_emoji else None
+        }
+
+    # Words used by sentiment_trend's word-based ratio, matched whole and
+    # case-insensitively
+    POSITIVE_WORDS = frozenset({
+        "love", "great", "awesome", "amazing", "happy", "thanks", "thank",
+        "glad", "excited", "perfect", "nice", "fun", "wonderful", "beautiful",
+        "congrats", "congratulations", "yay", "haha", "lol", "good", "best",
+    })
+    NEGATIVE_WORDS = frozenset({
+        "sad", "sorry", "angry", "upset", "hate", "bad", "worst", "annoyed",
+        "tired", "stressed", "terrible", "awful", "ugh", "disappointed",
+        "frustrated", "hurt", "miss", "worried", "sick", "mad",
+    })
+
+    def activity_heatmap(self, phone: Optional[str] = None, days: Optional[int] = None) -> List[List[int]]:
+        """
+        Count messages by day of week and hour of day.
+
+        Data for a contribution-graph style heatmap. Times are bucketed in
+        local time, like longest_conversation_day in
+        get_conversation_analytics. Reactions are ignored.
+
+        Args:
+            phone: Optional filter by contact (None = all conversations)
+            days: Optional limit to last N days (None = all time)
+
+        Returns:
+            List[List[int]]: 7 rows (0 = Sunday ... 6 = Saturday) of 24
+                hourly counts; all zeros if there are no messages
+
+        Example:
+            grid = interface.activity_heatmap("+14155551234", days=90)
+            print(f"Monday 9am: {grid[1][9]} messages")
+        """
+        logger.info(f"Getting activity heatmap (phone: {phone}, days: {days})")
+
+        grid = [[0] * 24 for _ in range(7)]
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return grid
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            filters = ["(m.associated_message_type IS NULL OR m.associated_message_type = 0)"]
+            params: List = []
+            if phone:
+                filters.append("h.id LIKE ?")
+                params.append(handle_like_pattern(phone))
+            if days:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                filters.append("m.date >= ?")
+                params.append(cocoa_timestamp(cutoff_date))
+
+            cursor.execute(f"""
+                SELECT
+                    CAST(strftime('%w', m.date / 1000000000 + 978307200, 'unixepoch', 'localtime') AS INTEGER) as dow,
+                    CAST(strftime('%H', m.date / 1000000000 + 978307200, 'unixepoch', 'localtime') AS INTEGER) as hour,
+                    COUNT(*)
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE {" AND ".join(filters)}
+                GROUP BY dow, hour
+            """, params)
+            for dow, hour, count in cursor.fetchall():
+                if dow is not None and hour is not None:
+                    grid[dow][hour] = count
+
+            conn.close()
+            return grid
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return grid
+        except Exception as e:
+            logger.error(f"Error getting activity heatmap: {e}")
+            return grid
+
+    # Common words ignored when detecting topics
+    TOPIC_STOP_WORDS = frozenset({
+        'that', 'this', 'with', 'from', 'have', 'just', 'what', 'when', 'where', 'would',
+        'could', 'should', 'about', 'their', 'there', 'these', 'those', 'been', 'were',
+        'will', 'your', 'some', 'them',
+    })
+
+    def top_topics(self, phone: str, days: Optional[int] = None, n_topics: int = 5) -> List[Dict]:
+        """
+        Find the main topics of a conversation by keyword clustering.
+
+        Each message is a document. Words of 4+ letters (minus
+        TOPIC_STOP_WORDS) that appear in at least two messages are scored
+        by TF-IDF summed over messages, so words that are frequent but not
+        everywhere rank highest. Starting from the best unused keyword, each
+        topic collects up to 4 more keywords that appear together with it in
+        at least half of the messages containing the rarer of the two.
+
+        Args:
+            phone: Phone number or iMessage handle
+            days: Optional limit to last N days (None = all time)
+            n_topics: Maximum number of topics
+
+        Returns:
+            List[Dict]: The n_topics topics covering the most messages,
+                most messages first, with keys:
+                - keywords: Topic keywords, most distinctive first
+                - message_count: Messages containing any of the keywords
+
+        Example:
+            for topic in interface.top_topics("+14155551234", days=90):
+                print(", ".join(topic['keywords']))
+        """
+        logger.info(f"Finding top {n_topics} topics for {phone} (days: {days})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            query = """
+                SELECT m.text, m.attributedBody
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+            """
+            params = [handle_like_pattern(phone)]
+            if days:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                query += " AND m.date >= ?"
+                params.append(cocoa_timestamp(cutoff_date))
+
+            cursor.execute(query, params)
+            documents = []
+            for text, attributed_body in cursor:
+                if not text and attributed_body:
+                    text = extract_text_from_blob(attributed_body)
+                words = [w for w in re.findall(r'\b[^\W\d_]{4,}\b', (text or "").lower())
+                         if w not in self.TOPIC_STOP_WORDS]
+                if words:
+                    documents.append(words)
+            conn.close()
+
+            doc_sets = [set(words) for words in documents]
+            doc_freq: Dict[str, int] = {}
+            for words in doc_sets:
+                for word in words:
+                    doc_freq[word] = doc_freq.get(word, 0) + 1
+
+            scores: Dict[str, float] = {}
+            for words in documents:
+                for word in set(words):
+                    if doc_freq[word] < 2:
+                        continue
+                    tf = words.count(word) / len(words)
+                    scores[word] = scores.get(word, 0.0) + tf * math.log(len(documents) / doc_freq[word])
+
+            candidates = [w for w, score in sorted(scores.items(), key=lambda x: x[1], reverse=True)
+                          if score > 0][:50]
+
+            topics = []
+            used = set()
+            for seed in candidates:
+                if seed in used:
+                    continue
+
+                keywords = [seed]
+                for word in candidates:
+                    if len(keywords) >= 5:
+                        break
+                    if word in used or word in keywords:
+                        continue
+                    together = sum(1 for words in doc_sets if seed in words and word in words)
+                    if together * 2 >= min(doc_freq[seed], doc_freq[word]):
+                        keywords.append(word)
+
+                used.update(keywords)
+                topics.append({
+                    "keywords": keywords,
+                    "message_count": sum(1 for words in doc_sets if words.intersection(keywords))
+                })
+
+            topics.sort(key=lambda t: t["message_count"], reverse=True)
+            return topics[:n_topics]
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error finding topics: {e}")
+            return []
+
+    def sentiment_trend(self, phone: str, period: str = "monthly", periods: int = 6) -> List[Dict]:
+        """
+        Track a conversation's mood over time.
+
+        Combines a word-based ratio (POSITIVE_WORDS vs NEGATIVE_WORDS) with
+        the emoji ratio from emoji_sentiment_ratio per calendar period, to
+        show whether a relationship is warming up or cooling down.
+        Reactions are ignored.
+
+        Args:
+            phone: Phone number or iMessage handle
+            period: "daily", "weekly" or "monthly"
+            periods: Number of periods, ending with the current one
+
+        Returns:
+            List[Dict]: One entry per period, oldest first, with keys:
+                - label: Period label, e.g. "2026-W03" or "2026-01"
+                - positive_ratio: Messages with positive words / messages
+                  with positive or negative words (None if neither)
+                - emoji_sentiment_ratio: Same ratio using emoji (None if
+                  no message has a sentiment emoji)
+                - message_count: Messages in the period
+
+        Example:
+            for p in interface.sentiment_trend("+14155551234", "monthly", 6):
+                print(p['label'], p['positive_ratio'])
+        """
+        logger.info(f"Getting {period} sentiment trend for {phone} ({periods} periods)")
+
+        windows = self._period_windows(period, periods)
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            start_cocoa = cocoa_timestamp(windows[0][1])
+
+            cursor.execute("""
+                SELECT m.text, m.attributedBody, m.date
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND m.date >= ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date ASC
+            """, (handle_like_pattern(phone), start_cocoa))
+            rows = cursor.fetchall()
+            conn.close()
+
+            texts: List[List[str]] = [[] for _ in windows]
+            index = 0
+            for text, attributed_body, date_cocoa in rows:
+                date = parse_timestamp_flexible(date_cocoa)
+                while index < len(windows) - 1 and date >= windows[index][2]:
+                    index += 1
+                if not text and attributed_body:
+                    text = extract_text_from_blob(attributed_body)
+                texts[index].append(text or "")
+
+            trend = []
+            for (label, _, _), period_texts in zip(windows, texts):
+                positive = negative = 0
+                for text in period_texts:
+                    words = set(re.findall(r"[a-z']+", text.lower()))
+                    positive += bool(words & self.POSITIVE_WORDS)
+                    negative += bool(words & self.NEGATIVE_WORDS)
+
+                trend.append({
+                    "label": label,
+                    "positive_ratio": round(positive / (positive + negative), 3) if positive + negative else None,
+                    "emoji_sentiment_ratio": self._emoji_sentiment(period_texts)["positive_ratio"],
+                    "message_count": len(period_texts)
+                })
+
+            return trend
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting sentiment trend: {e}")
+            return []
+
+    def compare_conversations(self, phone_a: str, phone_b: str, days: int = 30) -> Dict:
+        """
+        Compare communication patterns with two contacts side by side.
+
+        Args:
+            phone_a: First phone number or iMessage handle
+            phone_b: Second phone number or iMessage handle
+            days: Number of days to analyze
+
+        Returns:
+            Dict: Comparison with keys:
+                - a, b: get_conversation_analytics results
+                - a_response_times, b_response_times: response_time_analytics results
+                - a_last_message, b_last_message: last_messaged results
+                - winner_by_volume: phone_a, phone_b or "tie", by total messages
+                - winner_by_recency: phone_a, phone_b or "tie", by latest
+                  message ever (None if neither has messages)
+
+        Example:
+            diff = interface.compare_conversations("+14155551234", "+14155555678")
+            print(f"More messages with {diff['winner_by_volume']}")
+        """
+        logger.info(f"Comparing conversations with {phone_a} and {phone_b} (days: {days})")
+
+        a = self.get_conversation_analytics(phone_a, days=days)
+        b = self.get_conversation_analytics(phone_b, days=days)
+        a_last = self.last_messaged(phone_a)
+        b_last = self.last_messaged(phone_b)
+
+        def winner(value_a, value_b):
+            if value_a == value_b:
+                return "tie"
+            return phone_a if value_a > value_b else phone_b
+
+        return {
+            "a": a,
+            "b": b,
+            "a_response_times": self.response_time_analytics(phone_a, days=days),
+            "b_response_times": self.response_time_analytics(phone_b, days=days),
+            "a_last_message": a_last,
+            "b_last_message": b_last,
+            "winner_by_volume": winner(a.get("total_messages", 0), b.get("total_messages", 0)),
+            "winner_by_recency": winner(a_last or "", b_last or "") if a_last or b_last else None,
+            "analysis_period_days": days
+        }
+
+    def full_analytics_bundle(self, phone: str, contacts_manager, days: int = 30) -> Dict:
+        """
+        Collect every analytics view of one conversation in a single call.
+
+        Intended for MCP clients that want a complete picture of a
+        relationship to summarize, without a round trip per metric.
+
+        Args:
+            phone: Phone number or iMessage handle
+            contacts_manager: ContactsManager used to name the contact
+            days: Window for basic, response_times and per_hour
+
+        Returns:
+            Dict: Bundle with keys:
+                - phone, contact_name (None if not in contacts)
+                - basic: get_conversation_analytics result
+                - response_times: response_time_analytics result (with percentiles)
+                - per_month: stats_timeline result for the last 12 months
+                - per_hour: [{hour, count}] for local hours 0-23, matching
+                  activity_heatmap
+                - streak: {"longest_days", "longest_start", "current_days"},
+                  counting consecutive local calendar days with any message
+                - health: {"last_message", "days_since_last_message",
+                  "sent_received_ratio", "emoji_positive_ratio"}
+
+        Example:
+            bundle = interface.full_analytics_bundle("+14155551234", contacts)
+            print(bundle["streak"]["longest_days"])
+        """
+        logger.info(f"Building full analytics bundle for {phone}")
+
+        contact = contacts_manager.get_contact_by_phone(phone)
+        basic = self.get_conversation_analytics(phone, days=days)
+
+        bundle = {
+            "phone": phone,
+            "contact_name": contact.name if contact else None,
+            "basic": basic,
+            "response_times": self.response_time_analytics(phone, days=days, percentiles=True),
+            "per_month": self.stats_timeline(phone, period="monthly", periods=12),
+            "per_hour": [{"hour": hour, "count": 0} for hour in range(24)],
+            "streak": {"longest_days": 0, "longest_start": None, "current_days": 0},
+            "health": {}
+        }
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return bundle
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT m.date
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND m.date > 0
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date ASC
+            """, (handle_like_pattern(phone),))
+            dates = [parse_timestamp_flexible(row[0]) for row in cursor]
+            conn.close()
+
+            cutoff = datetime.now() - timedelta(days=days)
+            for date in dates:
+                if date >= cutoff:
+                    bundle["per_hour"][date.hour]["count"] += 1
+
+            # Streaks of consecutive calendar days with at least one message
+            active_days = sorted({d.date() for d in dates})
+            run_start, run_length = None, 0
+            for i, day in enumerate(active_days):
+                if i and (day - active_days[i - 1]).days == 1:
+                    run_length += 1
+                else:
+                    run_start, run_length = day, 1
+                if run_length > bundle["streak"]["longest_days"]:
+                    bundle["streak"]["longest_days"] = run_length
+                    bundle["streak"]["longest_start"] = run_start.isoformat()
+            today = datetime.now().date()
+            if active_days and (today - active_days[-1]).days <= 1:
+                bundle["streak"]["current_days"] = run_length
+
+            sent = basic.get("sent_count", 0)
+            received = basic.get("received_count", 0)
+            bundle["health"] = {
+                "last_message": dates[-1].isoformat() if dates else None,
+                "days_since_last_message": (datetime.now() - dates[-1]).days if dates else None,
+                "sent_received_ratio": round(sent / received, 2) if received else None,
+                "emoji_positive_ratio": self.emoji_sentiment_ratio(phone, days=days)["positive_ratio"]
+            }
+
+            return bundle
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return bundle
+        except Exception as e:
+            logger.error(f"Error building analytics bundle: {e}")
+            return bundle
+
+    # Messages fetched by llm_context before trimming to the token budget
+    LLM_CONTEXT_MAX_MESSAGES = 500
+
+    def llm_context(self, phone: str, max_tokens: int = 4000) -> Dict:
+        """
+        Build conversation context sized for an LLM's context window.
+
+        Fetches recent messages and drops the oldest until the estimated
+        token count fits the budget. Tokens are estimated as characters / 4,
+        rounded up per message, counting message text and the analytics summary.
+
+        Args:
+            phone: Phone number or iMessage handle
+            max_tokens: Token budget for the whole context
+
+        Returns:
+            Dict: Context with keys:
+                - messages: Messages that fit, oldest first, with the keys
+                  from get_recent_messages
+                - analytics_summary: One-line summary of the last 30 days
+                - token_estimate: Estimated tokens used (<= max_tokens unless
+                  the summary alone exceeds it)
+
+        Example:
+            context = interface.llm_context("+14155551234", max_tokens=2000)
+            prompt = context['analytics_summary'] + "\n" + "\n".join(
+                m['text'] for m in context['messages'])
+        """
+        logger.info(f"Building LLM context for {phone} (max tokens: {max_tokens})")
+
+        analytics = self.get_conversation_analytics(phone, days=30)
+        if analytics:
+            analytics_summary = (
+                f"Last 30 days: {analytics.get('total_messages', 0)} messages "
+                f"({analytics.get('sent_count', 0)} sent, {analytics.get('received_count', 0)} received)"
+            )
+            if analytics.get("busiest_day"):
+                analytics_summary += f", busiest on {analytics['busiest_day']}s"
+        else:
+            analytics_summary = "No analytics available"
+
+        token_estimate = (len(analytics_summary) + 3) // 4
+        messages = []
+        # Newest first, so the most recent messages are kept
+        for message in self.get_recent_messages(phone, limit=self.LLM_CONTEXT_MAX_MESSAGES):
+            tokens = (len(message.get("text") or "") + 3) // 4
+            if token_estimate + tokens > max_tokens:
+                break
+            token_estimate += tokens
+            messages.append(message)
+        messages.reverse()
+
+        return {
+            "messages": messages,
+            "analytics_summary": analytics_summary,
+            "token_estimate": token_estimate
+        }
+
+    def edited_message_count(self, phone: str) -> int:
+        """
+        Count messages in a conversation that were edited after sending.
+
+        Message editing arrived in macOS Ventura. Databases with a
+        message_edit_history table are counted from it; otherwise the
+        message.date_edited column is used, and older databases without
+        either return 0.
+
+        Args:
+            phone: Phone number or iMessage handle
+
+        Returns:
+            int: Number of distinct edited messages (0 on error)
+        """
+        logger.info(f"Counting edited messages for {phone}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return 0
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute(
+                "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'message_edit_history'"
+            )
+            has_history = cursor.fetchone() is not None
+
+            cursor.execute("PRAGMA table_info(message)")
+            has_date_edited = any(col[1] == "date_edited" for col in cursor.fetchall())
+
+            pattern = handle_like_pattern(phone)
+            if has_history:
+                cursor.execute("""
+                    SELECT COUNT(DISTINCT m.guid)
+                    FROM message m
+                    JOIN message_edit_history meh ON m.guid = meh.message_guid
+                    JOIN handle h ON m.handle_id = h.ROWID
+                    WHERE h.id LIKE ?
+                """, (pattern,))
+            elif has_date_edited:
+                cursor.execute("""
+                    SELECT COUNT(DISTINCT m.guid)
+                    FROM message m
+                    JOIN handle h ON m.handle_id = h.ROWID
+                    WHERE h.id LIKE ?
+                        AND m.date_edited > 0
+                """, (pattern,))
+            else:
+                logger.info("Database predates message editing (pre-Ventura)")
+                conn.close()
+                return 0
+
+            count = cursor.fetchone()[0] or 0
+            conn.close()
+            return count
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return 0
+        except Exception as e:
+            logger.error(f"Error counting edited messages: {e}")
+            return 0
+
+    def duplicate_messages(self, phone: str, time_window_secs: int = 60) -> List[Dict]:
+        """
+        Find messages that were sent twice in quick succession.
+
+        Retries occasionally deliver the same message twice. A message is
+        treated as a duplicate when an earlier message from the same handle
+        and direction has identical text and was sent no more than
+        time_window_secs before it. Repeats within the window are all paired
+        with the first copy.
+
+        Args:
+            phone: Phone number or iMessage handle
+            time_window_secs: Maximum gap between the two copies
+
+        Returns:
+            List[Dict]: Pairs in date order with keys original and duplicate
+                (each with guid, text, date, is_from_me) and seconds_apart
+
+        Example:
+            for pair in interface.duplicate_messages("+14155551234", 30):
+                print(pair["duplicate"]["guid"], pair["seconds_apart"])
+        """
+        logger.info(f"Finding duplicate messages for {phone} within {time_window_secs}s")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT
+                    m.guid,
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    m.handle_id
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                    AND m.date IS NOT NULL
+                ORDER BY m.date ASC
+            """, (handle_like_pattern(phone),))
+
+            rows = cursor.fetchall()
+            conn.close()
+
+            window_ns = time_window_secs * 1_000_000_000
+            originals = {}
+            pairs = []
+
+            for guid, text, attributed_body, date_cocoa, is_from_me, handle_id in rows:
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+                if not message_text:
+                    continue
+
+                date = parse_timestamp_flexible(date_cocoa)
+                message = {
+                    "guid": guid,
+                    "text": message_text,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                }
+
+                key = (handle_id, bool(is_from_me), message_text)
+                original = originals.get(key)
+                if original and date_cocoa - original[0] <= window_ns:
+                    pairs.append({
+                        "original": original[1],
+                        "duplicate": message,
+                        "seconds_apart": round((date_cocoa - original[0]) / 1_000_000_000, 1),
+                    })
+                else:
+                    originals[key] = (date_cocoa, message)
+
+            logger.info(f"Found {len(pairs)} duplicate messages")
+            return pairs
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error finding duplicate messages: {e}")
+            return []
+
+    def longest_gap_ever(self, phone: str) -> Optional[Dict]:
+        """
+        Find the longest silence in a conversation's entire history.
+
+        Pairs each message with the next one (reactions excluded) and
+        returns the widest gap.
+
+        Args:
+            phone: Phone number or iMessage handle
+
+        Returns:
+            Dict or None: The gap, or None with fewer than two messages:
+                - start: ISO timestamp of the last message before the gap
+                - end: ISO timestamp of the first message after the gap
+                - duration_hours: Gap length in hours (one decimal)
+        """
+        logger.info(f"Finding longest gap for {phone}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return None
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                WITH ordered AS (
+                    SELECT
+                        m.date AS start_date,
+                        LEAD(m.date) OVER (ORDER BY m.date) AS end_date
+                    FROM message m
+                    JOIN handle h ON m.handle_id = h.ROWID
+                    WHERE h.id LIKE ?
+                        AND m.date > 0
+                        AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                )
+                SELECT start_date, end_date
+                FROM ordered
+                WHERE end_date IS NOT NULL
+                ORDER BY end_date - start_date DESC
+                LIMIT 1
+            """, (handle_like_pattern(phone),))
+            row = cursor.fetchone()
+            conn.close()
+
+            if not row:
+                return None
+
+            start = parse_timestamp_flexible(row[0])
+            end = parse_timestamp_flexible(row[1])
+
+            return {
+                "start": start.isoformat(),
+                "end": end.isoformat(),
+                "duration_hours": round((end - start).total_seconds() / 3600, 1)
+            }
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return None
+        except Exception as e:
+            logger.error(f"Error finding longest gap: {e}")
+            return None
+
+    def batch_analytics(
+        self,
+        phones: List[str],
+        days: Optional[int] = 30
+    ) -> Dict[str, Dict]:
+        """
+        Get message counts for many contacts in a single query.
+
+        T0 Feature: Avoids running get_conversation_analytics once per
+        contact - every handle is matched and grouped in one pass.
+
+        Args:
+            phones: Phone numbers or iMessage handles to analyze
+            days: Number of days to analyze (None = all time)
+
+        Returns:
+            Dict[str, Dict]: Keyed by the input phone, each with:
+                - total_messages: Total message count
+                - sent_count: Messages you sent
+                - received_count: Messages received
+                - first_message: ISO timestamp of the earliest message
+                - last_message: ISO timestamp of the latest message
+            Phones with no messages in the period are omitted.
+
+        Example:
+            stats = interface.batch_analytics([c.phone for c in contacts], days=90)
+            for phone, s in stats.items():
+                print(f"{phone}: {s['total_messages']} messages")
+        """
+        logger.info(f"Getting batch analytics for {len(phones)} handles (days: {days})")
+
+        if not phones:
+            return {}
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return {}
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+
+            # Match every requested phone against handles in one join, keyed
+            # by the caller's phone string so formatting differences don't
+            # leak into the result
+            values = ", ".join("(?, ?)" for _ in phones)
+            params = []
+            for phone in phones:
+                params.extend([phone, handle_like_pattern(phone)])
+
+            date_filter = ""
+            if days is not None:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                date_filter = "AND m.date >= ?"
+                params.append(cocoa_timestamp(cutoff_date))
+
+            cursor.execute(f"""
+                WITH wanted(phone, pattern) AS (VALUES {values})
+                SELECT
+                    w.phone,
+                    COUNT(DISTINCT m.ROWID) as total,
+                    COUNT(DISTINCT CASE WHEN m.is_from_me = 1 THEN m.ROWID END) as sent,
+                    COUNT(DISTINCT CASE WHEN m.is_from_me = 0 THEN m.ROWID END) as received,
+                    MIN(m.date) as first_date,
+                    MAX(m.date) as last_date
+                FROM wanted w
+                JOIN handle h ON h.id LIKE w.pattern
+                JOIN message m ON m.handle_id = h.ROWID
+                WHERE (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                {date_filter}
+                GROUP BY w.phone
+            """, params)
+
+            results = {}
+            for phone, total, sent, received, first_date, last_date in cursor.fetchall():
+                results[phone] = {
+                    "total_messages": total or 0,
+                    "sent_count": sent or 0,
+                    "received_count": received or 0,
+                    "first_message": parse_timestamp_flexible(first_date).isoformat() if first_date else None,
+                    "last_message": parse_timestamp_flexible(last_date).isoformat() if last_date else None
+                }
+
+            conn.close()
+            logger.info(f"Generated batch analytics for {len(results)} handles")
+            return results
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return {}
+        except Exception as e:
+            logger.error(f"Error getting batch analytics: {e}")
+            return {}
+
+    def group_chat_analytics(
+        self,
+        chat_guid: str,
+        days: Optional[int] = None
+    ) -> Dict:
+        """
+        Get per-participant message statistics for a group chat.
+
+        T0 Feature: See who drives the conversation in a group.
+
+        Args:
+            chat_guid: Group chat GUID or chat_identifier (group_id from list_group_chats)
+            days: Optional limit to last N days
+
+        Returns:
+            Dict: Group analytics including:
+                - chat_guid: The group identifier that was queried
+                - display_name: The group name if set
+                - total_messages: Messages in the group (reactions excluded)
+                - messages_by_handle: [{handle, message_count}] sorted by count
+                - first_message: Timestamp of the earliest message in range
+                - last_message: Timestamp of the latest message in range
+
+        Example:
+            stats = interface.group_chat_analytics("chat152668864985555509", days=30)
+            for p in stats['messages_by_handle']:
+                print(f"{p['handle']}: {p['message_count']}")
+        """
+        logger.info(f"Getting group chat analytics (chat: {chat_guid}, days: {days})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return {}
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT c.ROWID, c.display_name
+                FROM chat c
+                WHERE c.guid = ? OR c.chat_identifier = ?
+            """, (chat_guid, chat_guid))
+            chats = cursor.fetchall()
+
+            if not chats:
+                conn.close()
+                return {"chat_guid": chat_guid, "error": "Group chat not found"}
+
+            chat_ids = [row[0] for row in chats]
+            display_name = next((row[1] for row in chats if row[1]), None)
+
+            placeholders = ",".join("?" * len(chat_ids))
+            query = f"""
+                SELECT
+                    CASE WHEN m.is_from_me = 1 THEN 'me' ELSE COALESCE(h.id, 'unknown') END as sender,
+                    COUNT(*) as msg_count,
+                    MIN(m.date) as first_date,
+                    MAX(m.date) as last_date
+                FROM message m
+                JOIN chat_message_join cmj ON m.ROWID = cmj.message_id
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE cmj.chat_id IN ({placeholders})
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+            """
+            params = list(chat_ids)
+
+            if days:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                cutoff_cocoa = cocoa_timestamp(cutoff_date)
+                query += " AND m.date >= ?"
+                params.append(cutoff_cocoa)
+
+            query += " GROUP BY sender ORDER BY msg_count DESC"
+
+            cursor.execute(query, params)
+            rows = cursor.fetchall()
+            conn.close()
+
+            first_cocoa = min((row[2] for row in rows if row[2]), default=None)
+            last_cocoa = max((row[3] for row in rows if row[3]), default=None)
+
+            first_message = parse_timestamp_flexible(first_cocoa)
+
+            last_message = parse_timestamp_flexible(last_cocoa)
+
+            analytics = {
+                "chat_guid": chat_guid,
+                "display_name": display_name,
+                "total_messages": sum(row[1] for row in rows),
+                "messages_by_handle": [
+                    {"handle": row[0], "message_count": row[1]} for row in rows
+                ],
+                "first_message": first_message.isoformat() if first_message else None,
+                "last_message": last_message.isoformat() if last_message else None
+            }
+
+            logger.info(f"Generated group analytics: {analytics['total_messages']} messages")
+            return analytics
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return {}
+        except Exception as e:
+            logger.error(f"Error getting group analytics: {e}")
+            return {}
+
+    def group_message_breakdown(self, chat_guid: str, days: Optional[int] = None) -> Dict[str, int]:
+        """
+        Count messages per participant in a group chat.
+
+        A lighter view of group_chat_analytics for showing who talks most
+        and who mostly lurks.
+
+        Args:
+            chat_guid: Group chat GUID or chat_identifier (group_id from list_group_chats)
+            days: Optional limit to last N days
+
+        Returns:
+            Dict[str, int]: handle -> message count, busiest first ("me" for
+                your own messages); empty if the chat isn't found
+        """
+        analytics = self.group_chat_analytics(chat_guid, days=days)
+        return {
+            p["handle"]: p["message_count"]
+            for p in analytics.get("messages_by_handle", [])
+        }
+
+    def rolling_window_count(self, days: int = 7) -> Dict:
+        """
+        Summarize message volume over the last N days across all conversations.
+
+        T0 Feature: "How active have I been lately?" at a glance.
+
+        Args:
+            days: Size of the rolling window in days
+
+        Returns:
+            Dict: Window summary including:
+                - period_days: The window size
+                - total: Messages in the window (reactions excluded)
+                - daily_average: total / period_days
+                - peak_day: Busiest calendar day (YYYY-MM-DD), or None
+                - peak_count: Messages on the busiest day
+
+        Example:
+            window = interface.rolling_window_count(days=7)
+            print(f"{window['total']} messages, peak {window['peak_day']}")
+        """
+        logger.info(f"Getting rolling window count (days: {days})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return {}
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cutoff_date = datetime.now() - timedelta(days=days)
+            cutoff_cocoa = cocoa_timestamp(cutoff_date)
+
+            # 978307200 = seconds between the Unix and Cocoa epochs
+            cursor.execute("""
+                SELECT
+                    date(m.date / 1000000000 + 978307200, 'unixepoch', 'localtime') as day,
+                    COUNT(*) as count
+                FROM message m
+                WHERE m.date >= ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                GROUP BY day
+                ORDER BY count DESC
+            """, (cutoff_cocoa,))
+            rows = cursor.fetchall()
+
+            conn.close()
+
+            total = sum(count for _, count in rows)
+            peak_day, peak_count = rows[0] if rows else (None, 0)
+
+            window = {
+                "period_days": days,
+                "total": total,
+                "daily_average": round(total / max(days, 1), 1),
+                "peak_day": peak_day,
+                "peak_count": peak_count
+            }
+
+            logger.info(f"Rolling window: {total} messages over {days} days")
+            return window
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return {}
+        except Exception as e:
+            logger.error(f"Error getting rolling window count: {e}")
+            return {}
+
+    # ===== T1 FEATURES =====
+
+    def get_message_thread(
+        self,
+        message_guid: Optional[str] = None,
+        thread_originator_guid: Optional[str] = None,
+        limit: int = 50
+    ) -> List[Dict]:
+        """
+        Get messages in a reply thread.
+
+        T1 Feature: Follow reply chains and inline replies.
+
+        Args:
+            message_guid: GUID of any message in the thread
+            thread_originator_guid: GUID of the thread starter (if known)
+            limit: Maximum messages to return
+
+        Returns:
+            List[Dict]: Messages in the thread, chronologically ordered:
+                - text: Message content
+                - date: Timestamp
+                - is_from_me: Boolean
+                - sender_handle: Who sent it
+                - is_thread_originator: Whether this started the thread
+                - reply_to_guid: What message this replies to
+
+        Example:
+            thread = interface.get_message_thread(message_guid="...")
+            for msg in thread:
+                prefix = "📌 " if msg['is_thread_originator'] else "  └ "
+                print(f"{prefix}{msg['sender_handle']}: {msg['text'][:50]}")
+        """
+        logger.info(f"Getting message thread (guid: {message_guid})")
+
+        if not message_guid and not thread_originator_guid:
+            logger.error("Either message_guid or thread_originator_guid required")
+            return []
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            # If we have message_guid but not thread_originator, find the originator
+            if message_guid and not thread_originator_guid:
+                cursor.execute("""
+                    SELECT thread_originator_guid, guid
+                    FROM message
+                    WHERE guid = ?
+                """, (message_guid,))
+                row = cursor.fetchone()
+                if row:
+                    thread_originator_guid = row[0] or row[1]
+
+            if not thread_originator_guid:
+                conn.close()
+                return []
+
+            # Get all messages in this thread
+            cursor.execute("""
+                SELECT
+                    m.guid,
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    h.id as sender_handle,
+                    m.thread_originator_guid,
+                    m.reply_to_guid
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE m.guid = ?
+                   OR m.thread_originator_guid = ?
+                ORDER BY m.date ASC
+                LIMIT ?
+            """, (thread_originator_guid, thread_originator_guid, limit))
+
+            rows = cursor.fetchall()
+
+            messages = []
+            for row in rows:
+                (guid, text, attributed_body, date_cocoa, is_from_me,
+                 sender_handle, orig_guid, reply_guid) = row
+
+                # Extract text
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                # Convert timestamp
+                date = parse_timestamp_flexible(date_cocoa)
+
+                messages.append({
+                    "guid": guid,
+                    "text": message_text or "[message content not available]",
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "sender_handle": sender_handle or ("me" if is_from_me else "unknown"),
+                    "is_thread_originator": guid == thread_originator_guid,
+                    "reply_to_guid": reply_guid
+                })
+
+            conn.close()
+            logger.info(f"Found {len(messages)} messages in thread")
+            return messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting thread: {e}")
+            return []
+
+    def thread_reply_count(self, originator_guid: str) -> int:
+        """
+        Count the inline replies to a message.
+
+        Combined with get_group_messages, this surfaces the most-discussed
+        messages in a group chat.
+
+        Args:
+            originator_guid: GUID of the message that started the thread
+
+        Returns:
+            int: Messages whose thread_originator_guid is originator_guid
+                (0 if none or on error)
+
+        Example:
+            replies = interface.thread_reply_count(msg['guid'])
+        """
+        logger.info(f"Counting replies to {originator_guid}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return 0
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute(
+                "SELECT COUNT(*) FROM message WHERE thread_originator_guid = ?",
+                (originator_guid,)
+            )
+            count = cursor.fetchone()[0]
+
+            conn.close()
+            return count
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return 0
+        except Exception as e:
+            logger.error(f"Error counting thread replies: {e}")
+            return 0
+
+    def thread_reply_counts(self, originator_guids: List[str]) -> Dict[str, int]:
+        """
+        Count the inline replies to several messages in one query.
+
+        The batch form of thread_reply_count, for annotating a page of
+        messages without opening a connection per message.
+
+        Args:
+            originator_guids: GUIDs of messages that may have started a thread
+
+        Returns:
+            Dict[str, int]: Reply count per GUID (0 for messages without
+                replies; empty on error)
+
+        Example:
+            counts = interface.thread_reply_counts([m['guid'] for m in messages])
+        """
+        guids = list(dict.fromkeys(g for g in originator_guids if g))
+        logger.info(f"Counting replies to {len(guids)} messages")
+
+        if not guids:
+            return {}
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return {}
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            placeholders = ",".join("?" * len(guids))
+            cursor.execute(f"""
+                SELECT thread_originator_guid, COUNT(*)
+                FROM message
+                WHERE thread_originator_guid IN ({placeholders})
+                GROUP BY thread_originator_guid
+            """, guids)
+            counts = dict.fromkeys(guids, 0)
+            counts.update(cursor.fetchall())
+
+            conn.close()
+            return counts
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return {}
+        except Exception as e:
+            logger.error(f"Error counting thread replies: {e}")
+            return {}
+
+    def conversations_with_threads(self, limit: int = 50) -> List[Dict]:
+        """
+        List messages that started a reply thread.
+
+        T1 Feature: Discover threads to expand with get_message_thread().
+
+        Args:
+            limit: Maximum thread roots to return
+
+        Returns:
+            List[Dict]: Thread roots, most recently active first:
+                - guid: GUID of the originating message
+                - text: Originating message content
+                - date: When the thread was started
+                - is_from_me: Whether you started the thread
+                - sender_handle: Who started the thread
+                - reply_count: Number of replies in the thread
+                - last_reply_date: When the most recent reply was sent
+
+        Example:
+            for root in interface.conversations_with_threads():
+                print(f"{root['reply_count']} replies: {root['text'][:50]}")
+        """
+        logger.info(f"Listing thread originators (limit: {limit})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT
+                    root.guid,
+                    root.text,
+                    root.attributedBody,
+                    root.date,
+                    root.is_from_me,
+                    h.id as sender_handle,
+                    t.reply_count,
+                    t.last_reply
+                FROM (
+                    SELECT
+                        m.thread_originator_guid as originator_guid,
+                        COUNT(*) as reply_count,
+                        MAX(m.date) as last_reply
+                    FROM message m
+                    WHERE m.thread_originator_guid IS NOT NULL
+                    GROUP BY m.thread_originator_guid
+                    HAVING COUNT(*) > 1
+                ) t
+                JOIN message root ON root.guid = t.originator_guid
+                LEFT JOIN handle h ON root.handle_id = h.ROWID
+                ORDER BY t.last_reply DESC
+                LIMIT ?
+            """, (limit,))
+
+            rows = cursor.fetchall()
+
+            threads = []
+            for row in rows:
+                (guid, text, attributed_body, date_cocoa, is_from_me,
+                 sender_handle, reply_count, last_reply_cocoa) = row
+
+                # Extract text
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                # Convert timestamps
+                date = parse_timestamp_flexible(date_cocoa)
+
+                last_reply = parse_timestamp_flexible(last_reply_cocoa)
+
+                threads.append({
+                    "guid": guid,
+                    "text": message_text or "[message content not available]",
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "sender_handle": sender_handle or ("me" if is_from_me else "unknown"),
+                    "reply_count": reply_count,
+                    "last_reply_date": last_reply.isoformat() if last_reply else None
+                })
+
+            conn.close()
+            logger.info(f"Found {len(threads)} threads")
+            return threads
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error listing threads: {e}")
+            return []
+
+    # URLs shared in message text
+    URL_PATTERN = re.compile(r'https?://[^\s<>"{}|\\^`\[\]]+')
+
+    def extract_links(
+        self,
+        phone: Optional[str] = None,
+        days: Optional[int] = None,
+        limit: int = 100
+    ) -> List[Dict]:
+        """
+        Extract URLs shared in conversations.
+
+        T1 Feature: Find all links that have been shared.
+
+        Args:
+            phone: Optional filter by contact
+            days: Optional filter by recency
+            limit: Maximum links to return
+
+        Returns:
+            List[Dict]: Link information including:
+                - url: The extracted URL
+                - message_text: Context from the message
+                - date: When shared
+                - is_from_me: Whether you shared it
+                - sender_handle: Who shared it
+
+        Example:
+            links = interface.extract_links(days=7)
+            for link in links:
+                print(f"{link['sender_handle']} shared: {link['url']}")
+        """
+        logger.info(f"Extracting links (phone: {phone}, days: {days})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cutoff_cocoa = None
+            if days:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                cutoff_cocoa = cocoa_timestamp(cutoff_date)
+
+            filters = []
+            params_base = []
+            if phone:
+                filters.append("h.id LIKE ?")
+                params_base.append(handle_like_pattern(phone))
+            if cutoff_cocoa is not None:
+                filters.append("m.date >= ?")
+                params_base.append(cutoff_cocoa)
+            filter_sql = (" AND " + " AND ".join(filters)) if filters else ""
+
+            links: List[Dict] = []
 
             def add_links_from_text(message_text: str, date_cocoa: Optional[int], is_from_me: int, sender_handle: Optional[str]):
                 if not message_text:
                     return
 
-                urls = url_pattern.findall(message_text)
+                urls = self.URL_PATTERN.findall(message_text)
                 if not urls:
                     return
 
-                date = None
-                if date_cocoa:
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
+                date = parse_timestamp_flexible(date_cocoa)
 
                 for url in urls:
                     url = url.rstrip('.,;:!?)')
@@ -1807,51 +5190,239 @@ class MessagesInterface:
                 {filter_sql}
                 ORDER BY m.date DESC
                 LIMIT ?
-                """,
-                (*params_base, limit * 4),
-            )
-            for text, date_cocoa, is_from_me, sender_handle in cursor.fetchall():
-                add_links_from_text(text or "", date_cocoa, is_from_me, sender_handle)
-                if len(links) >= limit:
-                    break
+                """,
+                (*params_base, limit * 4),
+            )
+            for text, date_cocoa, is_from_me, sender_handle in cursor.fetchall():
+                add_links_from_text(text or "", date_cocoa, is_from_me, sender_handle)
+                if len(links) >= limit:
+                    break
+
+            # Pass 2 (slower): messages with null text but data-detected; parse attributedBody.
+            remaining = limit - len(links)
+            if remaining > 0:
+                cursor.execute(
+                    f"""
+                    SELECT
+                        m.attributedBody,
+                        m.date,
+                        m.is_from_me,
+                        h.id as sender_handle
+                    FROM message m
+                    LEFT JOIN handle h ON m.handle_id = h.ROWID
+                    WHERE m.text IS NULL
+                      AND m.attributedBody IS NOT NULL
+                      AND m.was_data_detected = 1
+                    {filter_sql}
+                    ORDER BY m.date DESC
+                    LIMIT ?
+                    """,
+                    (*params_base, min(5000, remaining * 10)),
+                )
+                for attributed_body, date_cocoa, is_from_me, sender_handle in cursor.fetchall():
+                    message_text = extract_text_from_blob(attributed_body) if attributed_body else None
+                    if message_text:
+                        add_links_from_text(message_text, date_cocoa, is_from_me, sender_handle)
+                        if len(links) >= limit:
+                            break
+
+            conn.close()
+            logger.info(f"Found {len(links)} links")
+            return links
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error extracting links: {e}")
+            return []
+
+    def messages_with_urls(
+        self,
+        phone: Optional[str] = None,
+        limit: int = 50
+    ) -> List[Dict]:
+        """
+        Get messages that contain HTTP links.
+
+        Unlike extract_links, which returns one entry per URL, this returns
+        whole messages with their URLs attached. Like extract_links, it also
+        decodes attributedBody for data-detected messages with no plain text.
+
+        Args:
+            phone: Optional filter by contact
+            limit: Maximum messages to return
+
+        Returns:
+            List[Dict]: Messages, newest first, with keys:
+                - text, date, is_from_me, phone, is_group_chat, group_id
+                - urls: URLs found in the text, trailing punctuation removed
+
+        Example:
+            for msg in interface.messages_with_urls("+14155551234"):
+                print(msg['date'], msg['urls'])
+        """
+        logger.info(f"Getting messages with URLs (phone: {phone})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            handle_filter = ""
+            params: List = []
+            if phone:
+                handle_filter = "AND h.id LIKE ?"
+                params.append(handle_like_pattern(phone))
+
+            # LIKE and was_data_detected narrow the scan; the regex below
+            # confirms an actual URL
+            cursor.execute(f"""
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    h.id,
+                    m.cache_roomnames
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE (m.text LIKE '%http%'
+                        OR (m.text IS NULL AND m.attributedBody IS NOT NULL AND m.was_data_detected = 1))
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                    {handle_filter}
+                ORDER BY m.date DESC
+            """, params)
+
+            messages = []
+            for text, attributed_body, date_cocoa, is_from_me, handle_id, cache_roomnames in cursor:
+                if not text and attributed_body:
+                    text = extract_text_from_blob(attributed_body)
+                if not text:
+                    continue
+                urls = [url.rstrip('.,;:!?)') for url in self.URL_PATTERN.findall(text)]
+                if not urls:
+                    continue
+
+                date = parse_timestamp_flexible(date_cocoa)
+                is_group_chat = is_group_chat_identifier(cache_roomnames)
+
+                messages.append({
+                    "text": text,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "phone": handle_id or "unknown",
+                    "is_group_chat": is_group_chat,
+                    "group_id": cache_roomnames if is_group_chat else None,
+                    "urls": urls
+                })
+                if len(messages) >= limit:
+                    break
+
+            conn.close()
+            logger.info(f"Found {len(messages)} messages with URLs")
+            return messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting messages with URLs: {e}")
+            return []
+
+    def messages_by_length(
+        self,
+        phone: Optional[str] = None,
+        min_len: Optional[int] = None,
+        max_len: Optional[int] = None,
+        limit: int = 50
+    ) -> List[Dict]:
+        """
+        Get messages whose text is at least and/or at most a number of characters.
+
+        Long messages are often forwarded content; very short ones are
+        one-word replies. Lengths are measured in SQL on message.text, so
+        messages stored only in attributedBody are not considered.
+
+        Args:
+            phone: Optional filter by contact
+            min_len: Minimum text length in characters (None = no minimum)
+            max_len: Maximum text length in characters (None = no maximum)
+            limit: Maximum messages to return
+
+        Returns:
+            List[Dict]: Messages, newest first, with keys:
+                - text, date, is_from_me, phone, is_group_chat, group_id
+                - length: Text length in characters
+
+        Example:
+            long_ones = interface.messages_by_length("+14155551234", min_len=500)
+        """
+        logger.info(f"Getting messages by length (phone: {phone}, min: {min_len}, max: {max_len})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            conditions = ""
+            params: List = []
+            if min_len is not None:
+                conditions += " AND length(m.text) >= ?"
+                params.append(min_len)
+            if max_len is not None:
+                conditions += " AND length(m.text) <= ?"
+                params.append(max_len)
+            if phone:
+                conditions += " AND h.id LIKE ?"
+                params.append(handle_like_pattern(phone))
+
+            cursor.execute(f"""
+                SELECT
+                    m.text,
+                    m.date,
+                    m.is_from_me,
+                    h.id,
+                    m.cache_roomnames
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE m.text IS NOT NULL
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                    {conditions}
+                ORDER BY m.date DESC
+                LIMIT ?
+            """, params + [limit])
 
-            # Pass 2 (slower): messages with null text but data-detected; parse attributedBody.
-            remaining = limit - len(links)
-            if remaining > 0:
-                cursor.execute(
-                    f"""
-                    SELECT
-                        m.attributedBody,
-                        m.date,
-                        m.is_from_me,
-                        h.id as sender_handle
-                    FROM message m
-                    LEFT JOIN handle h ON m.handle_id = h.ROWID
-                    WHERE m.text IS NULL
-                      AND m.attributedBody IS NOT NULL
-                      AND m.was_data_detected = 1
-                    {filter_sql}
-                    ORDER BY m.date DESC
-                    LIMIT ?
-                    """,
-                    (*params_base, min(5000, remaining * 10)),
-                )
-                for attributed_body, date_cocoa, is_from_me, sender_handle in cursor.fetchall():
-                    message_text = extract_text_from_blob(attributed_body) if attributed_body else None
-                    if message_text:
-                        add_links_from_text(message_text, date_cocoa, is_from_me, sender_handle)
-                        if len(links) >= limit:
-                            break
+            messages = []
+            for text, date_cocoa, is_from_me, handle_id, cache_roomnames in cursor.fetchall():
+                date = parse_timestamp_flexible(date_cocoa)
+                is_group_chat = is_group_chat_identifier(cache_roomnames)
+
+                messages.append({
+                    "text": text,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "phone": handle_id or "unknown",
+                    "is_group_chat": is_group_chat,
+                    "group_id": cache_roomnames if is_group_chat else None,
+                    "length": len(text)
+                })
 
             conn.close()
-            logger.info(f"Found {len(links)} links")
-            return links
+            logger.info(f"Found {len(messages)} messages in length range")
+            return messages
 
         except sqlite3.Error as e:
             logger.error(f"Database error: {e}")
             return []
         except Exception as e:
-            logger.error(f"Error extracting links: {e}")
+            logger.error(f"Error getting messages by length: {e}")
             return []
 
     def get_voice_messages(
@@ -1916,69 +5487,343 @@ class MessagesInterface:
 
             if phone:
                 query += " AND h.id LIKE ?"
-                params.append(f"%{sanitize_like_pattern(phone)}%")
+                params.append(handle_like_pattern(phone))
+
+            query += " ORDER BY m.date DESC LIMIT ?"
+            params.append(limit)
+
+            cursor.execute(query, params)
+            rows = cursor.fetchall()
+
+            voice_messages = []
+            for row in rows:
+                (filename, mime_type, total_bytes, date_cocoa,
+                 is_from_me, is_played, sender_handle) = row
+
+                # Convert timestamp
+                date = parse_timestamp_flexible(date_cocoa)
+
+                voice_messages.append({
+                    "attachment_path": filename,
+                    "mime_type": mime_type,
+                    "size_bytes": total_bytes,
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "is_played": bool(is_played),
+                    "sender_handle": sender_handle or ("me" if is_from_me else "unknown")
+                })
+
+            conn.close()
+            logger.info(f"Found {len(voice_messages)} voice messages")
+            return voice_messages
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting voice messages: {e}")
+            return []
+
+    def delivery_failures(self, days: Optional[int] = None, limit: int = 100) -> List[Dict]:
+        """
+        Get messages you sent that failed to deliver.
+
+        T2 Feature: Audit failed sends from automated workflows so they
+        can be retried or investigated.
+
+        Args:
+            days: Only look back this many days (None = all time)
+            limit: Maximum number of messages
+
+        Returns:
+            List[Dict]: Failed messages, newest first, with keys:
+                - text: Message content
+                - date: Timestamp
+                - phone: Recipient handle
+                - service: "iMessage", "SMS", etc.
+                - error_code: Value of message.error (non-zero)
+
+        Example:
+            for f in interface.delivery_failures(days=7):
+                print(f"{f['phone']} (error {f['error_code']}): {f['text']}")
+        """
+        logger.info(f"Getting delivery failures (days: {days})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            query = """
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    h.id,
+                    m.service,
+                    m.error
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE m.is_from_me = 1
+                    AND m.error != 0
+            """
+            params = []
+
+            if days is not None:
+                cutoff_date = datetime.now() - timedelta(days=days)
+                query += " AND m.date >= ?"
+                params.append(cocoa_timestamp(cutoff_date))
 
             query += " ORDER BY m.date DESC LIMIT ?"
             params.append(limit)
 
-            cursor.execute(query, params)
-            rows = cursor.fetchall()
+            cursor.execute(query, params)
+
+            failures = []
+            for text, attributed_body, date_cocoa, handle_id, service, error in cursor.fetchall():
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                date = parse_timestamp_flexible(date_cocoa)
+
+                failures.append({
+                    "text": message_text or "[message content not available]",
+                    "date": date.isoformat() if date else None,
+                    "phone": handle_id or "unknown",
+                    "service": service,
+                    "error_code": error
+                })
+
+            conn.close()
+            logger.info(f"Found {len(failures)} failed messages")
+            return failures
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting delivery failures: {e}")
+            return []
+
+    def get_scheduled_messages(self) -> List[Dict]:
+        """
+        Get scheduled messages that are pending send.
+
+        T1 Feature: View queued/scheduled messages (read-only).
+
+        Note: This is read-only - scheduled messages are created through
+        the Messages app UI, not programmatically.
+
+        Returns:
+            List[Dict]: Scheduled message information including:
+                - text: Message content
+                - scheduled_date: When it will be sent
+                - recipient_handle: Who it's being sent to
+                - schedule_state: Current state (pending, etc.)
+
+        Example:
+            scheduled = interface.get_scheduled_messages()
+            for msg in scheduled:
+                print(f"Scheduled for {msg['scheduled_date']}: {msg['text'][:50]}")
+        """
+        logger.info("Getting scheduled messages")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            # Query for scheduled messages (schedule_type = 2)
+            cursor.execute("""
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.schedule_state,
+                    h.id as recipient_handle
+                FROM message m
+                LEFT JOIN handle h ON m.handle_id = h.ROWID
+                WHERE m.schedule_type = 2
+                ORDER BY m.date ASC
+            """)
+
+            rows = cursor.fetchall()
+
+            scheduled = []
+            for row in rows:
+                text, attributed_body, date_cocoa, schedule_state, recipient = row
+
+                # Extract text
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                # Convert timestamp
+                date = parse_timestamp_flexible(date_cocoa)
+
+                scheduled.append({
+                    "text": message_text or "[message content not available]",
+                    "scheduled_date": date.isoformat() if date else None,
+                    "recipient_handle": recipient or "unknown",
+                    "schedule_state": schedule_state
+                })
+
+            conn.close()
+            logger.info(f"Found {len(scheduled)} scheduled messages")
+            return scheduled
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error getting scheduled messages: {e}")
+            return []
+
+    def get_messages_by_phone(self, phone: str, limit: int = 20) -> List[Dict]:
+        """
+        Get messages by phone number (without needing contact to be configured).
+
+        Sprint 2.5: Enhanced access for unknown numbers.
+
+        Args:
+            phone: Phone number or iMessage handle
+            limit: Number of recent messages to retrieve
+
+        Returns:
+            List[Dict]: Messages with that phone number
+        """
+        # This is essentially the same as get_recent_messages but with
+        # a clearer interface for the MCP tool
+        return self.get_recent_messages(phone=phone, limit=limit)
+
+    def random_messages(self, phone: str, n: int = 10, fast: bool = False) -> List[Dict]:
+        """
+        Get a random sample of messages from a conversation.
+
+        For corpus analysis and demos, where head/tail would be biased.
+        The default uses ORDER BY RANDOM(), which scans every message with
+        the handle. fast=True instead jumps to random ROWIDs and takes the
+        next matching message, which stays quick on large databases but
+        favours messages that follow long runs of other conversations.
+
+        Args:
+            phone: Phone number or iMessage handle
+            n: Number of messages to sample
+            fast: Use ROWID-based selection instead of a full scan
+
+        Returns:
+            List[Dict]: Up to n distinct messages, oldest first, with the
+                keys from get_recent_messages
+
+        Example:
+            sample = interface.random_messages("+14155551234", n=20)
+        """
+        logger.info(f"Sampling {n} messages for {phone} (fast: {fast})")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            select = """
+                SELECT
+                    m.ROWID,
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    m.cache_roomnames
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+            """
+            handle_pattern = handle_like_pattern(phone)
+
+            if fast:
+                cursor.execute("SELECT MIN(ROWID), MAX(ROWID) FROM message")
+                min_rowid, max_rowid = cursor.fetchone()
+                rows = {}
+                # Bounded so a handle with few messages can't loop forever
+                for _ in range(n * 5 if min_rowid is not None else 0):
+                    start = random.randint(min_rowid, max_rowid)
+                    cursor.execute(select + " AND m.ROWID >= ? ORDER BY m.ROWID LIMIT 1",
+                                   (handle_pattern, start))
+                    row = cursor.fetchone()
+                    if row:
+                        rows[row[0]] = row
+                    if len(rows) >= n:
+                        break
+                rows = list(rows.values())
+            else:
+                cursor.execute(select + " ORDER BY RANDOM() LIMIT ?", (handle_pattern, n))
+                rows = cursor.fetchall()
+
+            conn.close()
 
-            voice_messages = []
-            for row in rows:
-                (filename, mime_type, total_bytes, date_cocoa,
-                 is_from_me, is_played, sender_handle) = row
+            messages = []
+            for _, text, attributed_body, date_cocoa, is_from_me, cache_roomnames in sorted(
+                    rows, key=lambda r: r[3] or 0):
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
 
-                # Convert timestamp
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
+                date = parse_timestamp_flexible(date_cocoa)
+                is_group_chat = is_group_chat_identifier(cache_roomnames)
 
-                voice_messages.append({
-                    "attachment_path": filename,
-                    "mime_type": mime_type,
-                    "size_bytes": total_bytes,
+                messages.append({
+                    "text": message_text or "[message content not available]",
                     "date": date.isoformat() if date else None,
                     "is_from_me": bool(is_from_me),
-                    "is_played": bool(is_played),
-                    "sender_handle": sender_handle or ("me" if is_from_me else "unknown")
+                    "is_group_chat": is_group_chat,
+                    "group_id": cache_roomnames if is_group_chat else None
                 })
 
-            conn.close()
-            logger.info(f"Found {len(voice_messages)} voice messages")
-            return voice_messages
+            logger.info(f"Sampled {len(messages)} messages")
+            return messages
 
         except sqlite3.Error as e:
             logger.error(f"Database error: {e}")
             return []
         except Exception as e:
-            logger.error(f"Error getting voice messages: {e}")
+            logger.error(f"Error sampling messages: {e}")
             return []
 
-    def get_scheduled_messages(self) -> List[Dict]:
+    def get_messages_with_reactions(self, phone: str, limit: int = 20) -> List[Dict]:
         """
-        Get scheduled messages that are pending send.
+        Get messages with their tapback reactions nested under them.
 
-        T1 Feature: View queued/scheduled messages (read-only).
+        Reactions are excluded from the top-level list and attached to the
+        message they target instead, so a conversation reads without
+        "Loved “...”" entries scattered through it.
 
-        Note: This is read-only - scheduled messages are created through
-        the Messages app UI, not programmatically.
+        Args:
+            phone: Phone number or iMessage handle
+            limit: Number of recent (non-reaction) messages to retrieve
 
         Returns:
-            List[Dict]: Scheduled message information including:
-                - text: Message content
-                - scheduled_date: When it will be sent
-                - recipient_handle: Who it's being sent to
-                - schedule_state: Current state (pending, etc.)
+            List[Dict]: Messages, newest first, with the keys from
+                get_recent_messages plus:
+                - guid: Message GUID
+                - reactions: List of {"reaction_type", "emoji", "reactor_handle",
+                  "is_from_me"}, oldest first ("me" for your own reactions)
 
         Example:
-            scheduled = interface.get_scheduled_messages()
-            for msg in scheduled:
-                print(f"Scheduled for {msg['scheduled_date']}: {msg['text'][:50]}")
+            for m in interface.get_messages_with_reactions("+14155551234"):
+                for r in m["reactions"]:
+                    print(f"  {r['emoji']} {r['reactor_handle']}")
         """
-        logger.info("Getting scheduled messages")
+        logger.info(f"Retrieving messages with reactions for {phone}")
 
         if not self.messages_db_path.exists():
             logger.error(f"Messages database not found: {self.messages_db_path}")
@@ -1987,73 +5832,317 @@ class MessagesInterface:
         try:
             conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
             cursor = conn.cursor()
+            phone_pattern = handle_like_pattern(phone)
 
-            # Query for scheduled messages (schedule_type = 2)
             cursor.execute("""
                 SELECT
+                    m.guid,
                     m.text,
                     m.attributedBody,
                     m.date,
-                    m.schedule_state,
-                    h.id as recipient_handle
+                    m.is_from_me,
+                    m.cache_roomnames
                 FROM message m
-                LEFT JOIN handle h ON m.handle_id = h.ROWID
-                WHERE m.schedule_type = 2
-                ORDER BY m.date ASC
-            """)
-
-            rows = cursor.fetchall()
-
-            scheduled = []
-            for row in rows:
-                text, attributed_body, date_cocoa, schedule_state, recipient = row
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date DESC
+                LIMIT ?
+            """, (phone_pattern, limit))
 
-                # Extract text
+            messages = []
+            by_guid = {}
+            oldest_date = 0
+            for guid, text, attributed_body, date_cocoa, is_from_me, cache_roomnames in cursor.fetchall():
                 message_text = text
                 if not message_text and attributed_body:
                     message_text = extract_text_from_blob(attributed_body)
 
-                # Convert timestamp
-                if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
-                else:
-                    date = None
+                date = parse_timestamp_flexible(date_cocoa)
+                is_group_chat = is_group_chat_identifier(cache_roomnames)
 
-                scheduled.append({
+                message = {
+                    "guid": guid,
                     "text": message_text or "[message content not available]",
-                    "scheduled_date": date.isoformat() if date else None,
-                    "recipient_handle": recipient or "unknown",
-                    "schedule_state": schedule_state
-                })
+                    "date": date.isoformat() if date else None,
+                    "is_from_me": bool(is_from_me),
+                    "is_group_chat": is_group_chat,
+                    "group_id": cache_roomnames if is_group_chat else None,
+                    "reactions": []
+                }
+                messages.append(message)
+                if guid:
+                    by_guid[guid] = message
+                oldest_date = date_cocoa or 0
+
+            if messages:
+                # Reactions in a 1:1 chat carry the other person's handle,
+                # including the ones you sent
+                cursor.execute("""
+                    SELECT
+                        r.associated_message_guid,
+                        r.associated_message_type,
+                        r.is_from_me,
+                        h.id
+                    FROM message r
+                    JOIN handle h ON r.handle_id = h.ROWID
+                    WHERE h.id LIKE ?
+                        AND r.associated_message_type BETWEEN 2000 AND 2005
+                        AND r.date >= ?
+                    ORDER BY r.date ASC
+                """, (phone_pattern, oldest_date))
+
+                for target, reaction_code, is_from_me, reactor_handle in cursor.fetchall():
+                    # Targets look like "p:0/<GUID>" or "bp:<GUID>"
+                    target_guid = re.split(r"[/:]", target or "")[-1]
+                    parent = by_guid.get(target_guid)
+                    if not parent:
+                        continue
+
+                    reaction_type = self.REACTION_TYPES.get(reaction_code, f"unknown_{reaction_code}")
+                    parent["reactions"].append({
+                        "reaction_type": reaction_type,
+                        "emoji": self.REACTION_EMOJI.get(reaction_type, "?"),
+                        "reactor_handle": "me" if is_from_me else (reactor_handle or "unknown"),
+                        "is_from_me": bool(is_from_me)
+                    })
 
             conn.close()
-            logger.info(f"Found {len(scheduled)} scheduled messages")
-            return scheduled
+            logger.info(f"Retrieved {len(messages)} messages with reactions")
+            return messages
 
         except sqlite3.Error as e:
             logger.error(f"Database error: {e}")
             return []
         except Exception as e:
-            logger.error(f"Error getting scheduled messages: {e}")
+            logger.error(f"Error retrieving messages with reactions: {e}")
             return []
 
-    def get_messages_by_phone(self, phone: str, limit: int = 20) -> List[Dict]:
+    def conversation_size_estimate(self, phone: str) -> Dict:
         """
-        Get messages by phone number (without needing contact to be configured).
+        Estimate the memory needed to load a whole conversation.
 
-        Sprint 2.5: Enhanced access for unknown numbers.
+        Helps decide between a single export and a paged or streaming one.
+        The estimate is a rough heuristic: 200 bytes of overhead per
+        message plus twice the text size (decoded strings are larger than
+        their UTF-8 storage). Text held only in attributedBody (macOS
+        Ventura+) isn't measured, so treat the result as a lower bound.
 
         Args:
             phone: Phone number or iMessage handle
-            limit: Number of recent messages to retrieve
 
         Returns:
-            List[Dict]: Messages with that phone number
+            Dict: Size estimate with keys:
+                - message_count: Messages in the conversation
+                - text_bytes: Total bytes of the text column
+                - attachment_refs_count: Attachments referenced by those messages
+                - estimated_ram_bytes: message_count * 200 + text_bytes * 2
         """
-        # This is essentially the same as get_recent_messages but with
-        # a clearer interface for the MCP tool
-        return self.get_recent_messages(phone=phone, limit=limit)
+        logger.info(f"Estimating conversation size for {phone}")
+
+        estimate = {
+            "message_count": 0,
+            "text_bytes": 0,
+            "attachment_refs_count": 0,
+            "estimated_ram_bytes": 0
+        }
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return estimate
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+            pattern = handle_like_pattern(phone)
+
+            cursor.execute("""
+                SELECT COUNT(*), SUM(length(CAST(m.text AS BLOB)))
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+            """, (pattern,))
+            message_count, text_bytes = cursor.fetchone()
+
+            cursor.execute("""
+                SELECT COUNT(*)
+                FROM message_attachment_join maj
+                JOIN message m ON maj.message_id = m.ROWID
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+            """, (pattern,))
+            attachment_refs = cursor.fetchone()[0]
+
+            conn.close()
+
+            estimate["message_count"] = message_count or 0
+            estimate["text_bytes"] = text_bytes or 0
+            estimate["attachment_refs_count"] = attachment_refs or 0
+            estimate["estimated_ram_bytes"] = estimate["message_count"] * 200 + estimate["text_bytes"] * 2
+            return estimate
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return estimate
+        except Exception as e:
+            logger.error(f"Error estimating conversation size: {e}")
+            return estimate
+
+    def export_messages_csv(self, phone: str, writer: TextIO, limit: int = 1000) -> int:
+        """
+        Write a conversation as CSV to any text stream.
+
+        Library callers can pass an open file, io.StringIO or sys.stdout.
+        Columns match the keys returned by get_recent_messages.
+
+        Args:
+            phone: Phone number or iMessage handle
+            writer: Text stream to write to (open files need newline="")
+            limit: Maximum number of messages, newest first
+
+        Returns:
+            int: Number of rows written (excluding the header)
+
+        Example:
+            with open("john.csv", "w", newline="") as f:
+                count = interface.export_messages_csv("+14155551234", f)
+        """
+        messages = self.get_recent_messages(phone=phone, limit=limit)
+
+        csv_writer = csv.DictWriter(
+            writer,
+            fieldnames=["text", "date", "is_from_me", "is_group_chat", "group_id"]
+        )
+        csv_writer.writeheader()
+        csv_writer.writerows(messages)
+
+        logger.info(f"Exported {len(messages)} messages to CSV")
+        return len(messages)
+
+    # Formats accepted by bulk_export
+    EXPORT_FORMATS = ("json", "ndjson", "markdown")
+
+    def bulk_export(
+        self,
+        contacts_manager,
+        writer: TextIO,
+        fmt: str = "json",
+        limit_per: int = 1000
+    ) -> Dict:
+        """
+        Export the conversations of every contact to one text stream.
+
+        Contacts are written one after another, each with its messages
+        oldest first:
+            - json: a single array of {"name", "phone", "messages"} objects
+            - ndjson: one such object per line
+            - markdown: a "# {name}" heading per contact, then one line per
+              message
+
+        Args:
+            contacts_manager: ContactsManager whose contacts are exported
+            writer: Text stream to write to
+            fmt: One of EXPORT_FORMATS
+            limit_per: Maximum messages per contact, most recent kept
+
+        Returns:
+            Dict: Report with keys:
+                - contacts_exported: Contacts written
+                - messages_exported: Messages written across all contacts
+                - errors: "name: error" strings for contacts that failed
+
+        Example:
+            with open("all.md", "w") as f:
+                report = interface.bulk_export(contacts_manager, f, "markdown")
+        """
+        if fmt not in self.EXPORT_FORMATS:
+            raise ValueError(f"Unknown export format: {fmt} (expected one of {self.EXPORT_FORMATS})")
+
+        logger.info(f"Exporting {contacts_manager.count()} conversations as {fmt}")
+
+        report = {"contacts_exported": 0, "messages_exported": 0, "errors": []}
+
+        if fmt == "json":
+            writer.write("[")
+
+        for contact in contacts_manager.contacts:
+            try:
+                messages = list(reversed(self.get_recent_messages(contact.phone, limit=limit_per)))
+                document = {"name": contact.name, "phone": contact.phone, "messages": messages}
+
+                if fmt == "json":
+                    if report["contacts_exported"]:
+                        writer.write(",")
+                    writer.write("\n" + json.dumps(document, default=str))
+                elif fmt == "ndjson":
+                    writer.write(json.dumps(document, default=str) + "\n")
+                else:
+                    if report["contacts_exported"]:
+                        writer.write("\n")
+                    writer.write(f"# {contact.name}\n\n")
+                    for m in messages:
+                        sender = "Me" if m.get("is_from_me") else contact.name
+                        writer.write(f"- **{sender}** ({m.get('date') or 'unknown date'}): {m.get('text')}\n")
+            except Exception as e:
+                logger.error(f"Error exporting {contact.name}: {e}")
+                report["errors"].append(f"{contact.name}: {e}")
+                continue
+
+            report["contacts_exported"] += 1
+            report["messages_exported"] += len(messages)
+
+        if fmt == "json":
+            writer.write("\n]\n")
+
+        logger.info(f"Exported {report['messages_exported']} messages "
+                    f"for {report['contacts_exported']} contacts")
+        return report
+
+    def conversation_timeline(self, phone: str, limit: int = 50) -> List[Dict]:
+        """
+        Get a conversation as a render-ready timeline, oldest first.
+
+        Interleaves messages with day boundaries (whenever the calendar date
+        changes) and gap markers (when more than 7 days pass between
+        messages), so TUI and export renderers don't each reimplement it.
+
+        Args:
+            phone: Phone number or iMessage handle
+            limit: Number of recent messages to include
+
+        Returns:
+            List[Dict]: Timeline entries, each with a "type" key:
+                - "message": plus "message" (dict from get_recent_messages)
+                - "day_boundary": plus "label" (ISO date, e.g. "2026-01-15")
+                - "gap_marker": plus "label" (e.g. "12 days without messages")
+
+        Example:
+            for entry in interface.conversation_timeline("+14155551234"):
+                if entry["type"] == "day_boundary":
+                    print(f"--- {entry['label']} ---")
+        """
+        messages = self.get_recent_messages(phone=phone, limit=limit)
+
+        timeline = []
+        previous = None
+        for message in reversed(messages):
+            date = datetime.fromisoformat(message["date"]) if message.get("date") else None
+
+            if date and previous:
+                gap_days = (date - previous).days
+                if gap_days > 7:
+                    timeline.append({
+                        "type": "gap_marker",
+                        "label": f"{gap_days} days without messages"
+                    })
+            if date and (not previous or date.date() != previous.date()):
+                timeline.append({"type": "day_boundary", "label": date.date().isoformat()})
+
+            timeline.append({"type": "message", "message": message})
+            if date:
+                previous = date
+
+        return timeline
 
     # ===== T2 FEATURES =====
 
@@ -2111,12 +6200,11 @@ class MessagesInterface:
                     AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
                     AND m.item_type = 0
             """
-            params = [f"%{phone}%"]
+            params = [handle_like_pattern(phone)]
 
             if days:
                 cutoff_date = datetime.now() - timedelta(days=days)
-                cocoa_epoch = datetime(2001, 1, 1)
-                cutoff_cocoa = int((cutoff_date - cocoa_epoch).total_seconds() * 1_000_000_000)
+                cutoff_cocoa = cocoa_timestamp(cutoff_date)
                 query += " AND m.date >= ?"
                 params.append(cutoff_cocoa)
 
@@ -2155,8 +6243,7 @@ class MessagesInterface:
 
                 # Convert timestamp
                 if date_cocoa:
-                    cocoa_epoch = datetime(2001, 1, 1)
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
+                    date = parse_timestamp_flexible(date_cocoa)
                 else:
                     date = datetime.now()
 
@@ -2173,7 +6260,7 @@ class MessagesInterface:
                 # Simple word frequency for topic detection
                 words = re.findall(r'\b\w{4,}\b', message_text.lower())
                 for word in words:
-                    if word not in {'that', 'this', 'with', 'from', 'have', 'just', 'what', 'when', 'where', 'would', 'could', 'should', 'about', 'their', 'there', 'these', 'those', 'been', 'were', 'will', 'your', 'some', 'them'}:
+                    if word not in self.TOPIC_STOP_WORDS:
                         word_freq[word] = word_freq.get(word, 0) + 1
 
                 messages.append({
@@ -2286,11 +6373,34 @@ class MessagesInterface:
         ],
     }
 
+    # Base priority per follow-up category; each day of waiting adds 5, up to 100
+    FOLLOW_UP_PRIORITY_WEIGHTS = {
+        "unanswered_questions": 40,
+        "pending_promises": 35,
+        "waiting_on_them": 30,
+        "time_sensitive": 30,
+        "stale_conversations": 25,
+    }
+
+    # Minimum priority score for each label, highest first
+    FOLLOW_UP_PRIORITY_LABELS = [
+        (80, "Urgent"),
+        (60, "High"),
+        (40, "Medium"),
+        (0, "Low"),
+    ]
+
+    @classmethod
+    def follow_up_priority_label(cls, score: int) -> str:
+        """Map a follow-up priority score (0-100) to Urgent, High, Medium or Low."""
+        return next(label for minimum, label in cls.FOLLOW_UP_PRIORITY_LABELS if score >= minimum)
+
     def detect_follow_up_needed(
         self,
         days: int = 7,
         min_stale_days: int = 3,
-        limit: int = 50
+        limit: int = 50,
+        include_context: bool = False
     ) -> Dict:
         """
         Detect conversations that may need follow-up.
@@ -2301,6 +6411,9 @@ class MessagesInterface:
             days: Look back this many days for patterns
             min_stale_days: Flag conversations with no reply after this many days
             limit: Maximum items per category
+            include_context: Add "context_messages" to every item - the last
+                3 messages with that handle (either direction), oldest first,
+                each with text, date and is_from_me
 
         Returns:
             Dict: Follow-up needs organized by category:
@@ -2309,6 +6422,9 @@ class MessagesInterface:
                 - waiting_on_them: Things you're waiting on from them
                 - stale_conversations: Important conversations gone quiet
                 - time_sensitive: Messages with time references
+                Every item has "priority_score" (0-100, from its category
+                and how many days it has waited) and "priority_label"
+                ("Urgent", "High", "Medium" or "Low").
 
         Example:
             follow_ups = interface.detect_follow_up_needed()
@@ -2328,9 +6444,8 @@ class MessagesInterface:
             # Calculate date thresholds
             cutoff_date = datetime.now() - timedelta(days=days)
             stale_date = datetime.now() - timedelta(days=min_stale_days)
-            cocoa_epoch = datetime(2001, 1, 1)
-            cutoff_cocoa = int((cutoff_date - cocoa_epoch).total_seconds() * 1_000_000_000)
-            stale_cocoa = int((stale_date - cocoa_epoch).total_seconds() * 1_000_000_000)
+            cutoff_cocoa = cocoa_timestamp(cutoff_date)
+            stale_cocoa = cocoa_timestamp(stale_date)
 
             results = {
                 "unanswered_questions": [],
@@ -2386,7 +6501,7 @@ class MessagesInterface:
                     continue
 
                 if date_cocoa:
-                    date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
+                    date = parse_timestamp_flexible(date_cocoa)
                 else:
                     date = datetime.now()
 
@@ -2490,6 +6605,27 @@ class MessagesInterface:
 
             conn.close()
 
+            if include_context:
+                for category, items in results.items():
+                    if not isinstance(items, list):
+                        continue
+                    for item in items:
+                        recent = conversations.get(item["phone"], [])[:3]
+                        item["context_messages"] = [
+                            {
+                                "text": m["text"],
+                                "date": m["date"].isoformat(),
+                                "is_from_me": m["is_from_me"]
+                            }
+                            for m in reversed(recent)
+                        ]
+
+            for category, weight in self.FOLLOW_UP_PRIORITY_WEIGHTS.items():
+                for item in results[category]:
+                    waited = item.get("days_ago", item.get("days_waiting", item.get("days_since_reply", 0)))
+                    item["priority_score"] = min(100, weight + 5 * waited)
+                    item["priority_label"] = self.follow_up_priority_label(item["priority_score"])
+
             # Add summary counts
             results["summary"] = {
                 "unanswered_questions": len(results["unanswered_questions"]),
@@ -2505,15 +6641,169 @@ class MessagesInterface:
                 ])
             }
 
-            logger.info(f"Found {results['summary']['total_action_items']} follow-up items")
+            logger.info(f"Found {results['summary']['total_action_items']} follow-up items")
+            return results
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return {"error": str(e)}
+        except Exception as e:
+            logger.error(f"Error detecting follow-ups: {e}")
+            return {"error": str(e)}
+
+    def unknown_handle_followups(
+        self,
+        contacts_manager,
+        days: int = 7,
+        min_stale_days: int = 3,
+        include_context: bool = False
+    ) -> Dict:
+        """
+        Detect follow-ups from people who aren't in the contacts file.
+
+        Inbox zero for your address book: these are handles that messaged
+        you but haven't been added as contacts yet.
+
+        Args:
+            contacts_manager: ContactsManager used to recognize known handles
+            days: Look back this many days for patterns
+            min_stale_days: Flag conversations with no reply after this many days
+            include_context: Add "context_messages" to every item
+
+        Returns:
+            Dict: Same categories and summary as detect_follow_up_needed,
+                keeping only items whose phone matches no contact
+
+        Example:
+            unknown = interface.unknown_handle_followups(contacts_manager)
+            for q in unknown['unanswered_questions']:
+                print(f"Add {q['phone']}? They asked: {q['text'][:50]}")
+        """
+        followups = self.detect_follow_up_needed(days=days, min_stale_days=min_stale_days,
+                                                 include_context=include_context)
+        if "summary" not in followups:
+            return followups
+
+        summary = followups["summary"]
+        for category in summary:
+            if category in followups:
+                followups[category] = [
+                    item for item in followups[category]
+                    if not contacts_manager.get_contact_by_handle(item.get("phone") or "")
+                ]
+                summary[category] = len(followups[category])
+
+        summary["total_action_items"] = sum(
+            summary[k] for k in
+            ("unanswered_questions", "pending_promises", "waiting_on_them", "stale_conversations")
+        )
+
+        logger.info(f"Found {summary['total_action_items']} follow-up items from unknown handles")
+        return followups
+
+    def unacknowledged_outbound(
+        self,
+        phone: str,
+        days: int = 30,
+        min_wait_hours: int = 24
+    ) -> List[Dict]:
+        """
+        Find messages you sent that never got a response.
+
+        T2 Feature: The inverse of follow-up detection - an outbound message
+        counts as unacknowledged when it is older than min_wait_hours and no
+        inbound message from the same handle arrived within min_wait_hours
+        after it.
+
+        Args:
+            phone: Phone number or iMessage handle
+            days: Look back this many days
+            min_wait_hours: Hours to wait for a reply before flagging
+
+        Returns:
+            List[Dict]: Unacknowledged messages, oldest first, with keys:
+                - text: Message content
+                - date: ISO timestamp
+                - is_from_me: Always True
+                - phone: Handle the message was sent to
+                - hours_waiting: Hours elapsed since the message was sent
+
+        Example:
+            ignored = interface.unacknowledged_outbound("+14155551234", days=14)
+            for msg in ignored:
+                print(f"{msg['hours_waiting']}h: {msg['text'][:50]}")
+        """
+        logger.info(f"Finding unacknowledged messages to {phone} (days: {days}, wait: {min_wait_hours}h)")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cutoff_cocoa = cocoa_timestamp(datetime.now() - timedelta(days=days))
+
+            cursor.execute("""
+                SELECT
+                    m.text,
+                    m.attributedBody,
+                    m.date,
+                    m.is_from_me,
+                    h.id
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND m.date >= ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date ASC
+            """, (handle_like_pattern(phone), cutoff_cocoa))
+
+            rows = cursor.fetchall()
+            conn.close()
+
+            now = datetime.now()
+            wait = timedelta(hours=min_wait_hours)
+            inbound_dates = [
+                parse_timestamp_flexible(row[2])
+                for row in rows if row[2] and not row[3]
+            ]
+
+            results = []
+            for text, attributed_body, date_cocoa, is_from_me, handle in rows:
+                if not is_from_me or not date_cocoa:
+                    continue
+
+                sent = parse_timestamp_flexible(date_cocoa)
+                if now - sent < wait:
+                    continue
+
+                replied = any(sent < received <= sent + wait for received in inbound_dates)
+                if replied:
+                    continue
+
+                message_text = text
+                if not message_text and attributed_body:
+                    message_text = extract_text_from_blob(attributed_body)
+
+                results.append({
+                    "text": message_text or "[message content not available]",
+                    "date": sent.isoformat(),
+                    "is_from_me": True,
+                    "phone": handle,
+                    "hours_waiting": int((now - sent).total_seconds() // 3600)
+                })
+
+            logger.info(f"Found {len(results)} unacknowledged messages")
             return results
 
         except sqlite3.Error as e:
             logger.error(f"Database error: {e}")
-            return {"error": str(e)}
+            return []
         except Exception as e:
-            logger.error(f"Error detecting follow-ups: {e}")
-            return {"error": str(e)}
+            logger.error(f"Error finding unacknowledged messages: {e}")
+            return []
 
     def list_recent_handles(self, days: int = 30, limit: int = 100) -> List[Dict]:
         """
@@ -2545,8 +6835,7 @@ class MessagesInterface:
 
             # Calculate cutoff date in Cocoa timestamp format
             cutoff = datetime.now() - timedelta(days=days)
-            cocoa_epoch = datetime(2001, 1, 1)
-            cutoff_cocoa = (cutoff - cocoa_epoch).total_seconds() * 1_000_000_000
+            cutoff_cocoa = cocoa_timestamp(cutoff)
 
             query = """
                 SELECT
@@ -2572,7 +6861,7 @@ class MessagesInterface:
 
                 # Convert Cocoa timestamp
                 if last_date_cocoa:
-                    last_date = cocoa_epoch + timedelta(seconds=last_date_cocoa / 1_000_000_000)
+                    last_date = parse_timestamp_flexible(last_date_cocoa)
                 else:
                     last_date = None
 
@@ -2595,6 +6884,399 @@ class MessagesInterface:
             logger.error(f"Error listing handles: {e}")
             return []
 
+    def handle_metadata(self, handle: str) -> Optional[Dict]:
+        """
+        Get service and country details for a phone/email handle.
+
+        Looks for an exact handle.id match first. Otherwise falls back to
+        the last 10 digits of the input, so "(415) 555-1234" finds a
+        handle stored as "+14155551234".
+
+        Args:
+            handle: Phone number or email as stored in the handle table
+
+        Returns:
+            Dict or None: Handle details if found:
+                - id: Handle identifier
+                - service: "iMessage", "SMS", etc.
+                - country: Country code recorded by Messages (e.g. "us"),
+                  or looked up from the number's calling code if blank
+                - uncanonicalized_id: Handle as originally entered
+        """
+        logger.info(f"Getting handle metadata for {handle}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return None
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            query = """
+                SELECT id, service, country, uncanonicalized_id
+                FROM handle
+                WHERE {condition}
+                ORDER BY ROWID
+                LIMIT 1
+            """
+
+            cursor.execute(query.format(condition="id = ?"), (handle,))
+            row = cursor.fetchone()
+
+            digits = "".join(c for c in handle if c.isdigit())
+            if not row and len(digits) >= 7:
+                cursor.execute(query.format(condition="id LIKE ?"), (handle_like_pattern(handle),))
+                row = cursor.fetchone()
+
+            conn.close()
+
+            if not row:
+                return None
+
+            handle_id, service, country, uncanonicalized_id = row
+            if not country and "@" not in handle_id:
+                code = phone_country_code(handle_id)
+                country = code.lower() if code else None
+
+            return {
+                "id": handle_id,
+                "service": service,
+                "country": country,
+                "uncanonicalized_id": uncanonicalized_id
+            }
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return None
+        except Exception as e:
+            logger.error(f"Error getting handle metadata: {e}")
+            return None
+
+    # handle.id with phone formatting stripped, so "+1 (415) 555-1234" compares as "14155551234"
+    NORMALIZED_HANDLE_EXPR = (
+        "REPLACE(REPLACE(REPLACE(REPLACE(REPLACE(REPLACE("
+        "id, ' ', ''), '(', ''), ')', ''), '-', ''), '.', ''), '+', '')"
+    )
+
+    def all_handles_for_phone(self, phone: str) -> List[str]:
+        """
+        Find every handle.id that refers to the same phone number.
+
+        The same number can be stored as "+14155551234", "14155551234",
+        "4155551234" or "+1 (415) 555-1234". Handles are compared with
+        formatting stripped, on the last 10 digits of the input. Emails
+        match case-insensitively.
+
+        Args:
+            phone: Phone number in any format, or an email handle
+
+        Returns:
+            List[str]: Matching raw handle IDs, in handle order (deduplicated;
+                the same ID can exist once per service)
+
+        Example:
+            handles = interface.all_handles_for_phone("(415) 555-1234")
+        """
+        logger.info(f"Finding all handles for {phone}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            digits = "".join(c for c in phone if c.isdigit())
+            if "@" in phone or len(digits) < 7:
+                cursor.execute("SELECT id FROM handle WHERE id = ? COLLATE NOCASE ORDER BY ROWID",
+                               (phone.strip(),))
+            else:
+                cursor.execute(
+                    f"SELECT id FROM handle WHERE {self.NORMALIZED_HANDLE_EXPR} LIKE ? ORDER BY ROWID",
+                    (handle_like_pattern(phone),)
+                )
+
+            handles = list(dict.fromkeys(row[0] for row in cursor.fetchall()))
+            conn.close()
+            return handles
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error finding handles: {e}")
+            return []
+
+    def search_handles(self, pattern: str) -> List[str]:
+        """
+        Find handle IDs containing a substring, case-insensitively.
+
+        For locating email handles or partial phone numbers without
+        knowing how they are stored. % and _ in the pattern match literally.
+
+        Args:
+            pattern: Substring to look for, e.g. "gmail" or "5551"
+
+        Returns:
+            List[str]: Distinct matching handle IDs, sorted
+
+        Example:
+            interface.search_handles("@icloud")
+        """
+        logger.info(f"Searching handles for '{pattern}'")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return []
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute(
+                "SELECT DISTINCT id FROM handle WHERE id LIKE ? ESCAPE '\\' ORDER BY id",
+                (f"%{sanitize_like_pattern(pattern)}%",)
+            )
+            handles = [row[0] for row in cursor.fetchall()]
+
+            conn.close()
+            return handles
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return []
+        except Exception as e:
+            logger.error(f"Error searching handles: {e}")
+            return []
+
+    def handle_first_seen(self, phone: str) -> Optional[str]:
+        """
+        Get when a handle first appeared in the Messages database.
+
+        Matches handles the same way as last_messaged, so together they
+        bound the whole history with a contact.
+
+        Args:
+            phone: Phone number or iMessage handle (email)
+
+        Returns:
+            ISO timestamp of the earliest message, or None if there are none
+        """
+        logger.info(f"Getting first message date for {phone}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return None
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT MIN(m.date)
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+            """, (handle_like_pattern(phone),))
+            first_cocoa = cursor.fetchone()[0]
+
+            conn.close()
+
+            if not first_cocoa:
+                return None
+            return parse_timestamp_flexible(first_cocoa).isoformat()
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return None
+        except Exception as e:
+            logger.error(f"Error getting first message date: {e}")
+            return None
+
+    def first_received_message(self, phone: str) -> Optional[Dict]:
+        """
+        Get the first message a contact ever sent you.
+
+        Where handle_first_seen covers both directions, this answers "what
+        was the first thing they said to me?". Handles are matched as in
+        handle_first_seen, and reactions are skipped.
+
+        Args:
+            phone: Phone number or iMessage handle (email)
+
+        Returns:
+            Dict or None: {"text", "date", "phone"} for the earliest
+            received message, or None if they never messaged you
+
+        Example:
+            first = interface.first_received_message("+14155551234")
+        """
+        logger.info(f"Getting first received message for {phone}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return None
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT m.text, m.attributedBody, m.date, h.id
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND m.is_from_me = 0
+                    AND m.date > 0
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+                ORDER BY m.date ASC
+                LIMIT 1
+            """, (handle_like_pattern(phone),))
+            row = cursor.fetchone()
+
+            conn.close()
+
+            if not row:
+                return None
+
+            text, attributed_body, date_cocoa, handle_id = row
+            message_text = text
+            if not message_text and attributed_body:
+                message_text = extract_text_from_blob(attributed_body)
+
+            date = parse_timestamp_flexible(date_cocoa)
+            return {
+                "text": message_text or "[message content not available]",
+                "date": date.isoformat() if date else None,
+                "phone": handle_id
+            }
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return None
+        except Exception as e:
+            logger.error(f"Error getting first received message: {e}")
+            return None
+
+    def last_messaged(self, phone: str) -> Optional[str]:
+        """
+        Get when you last exchanged a message with a handle, in either direction.
+
+        Formatted numbers like "(415) 555-1234" are matched on their last
+        10 digits, as in handle_metadata.
+
+        Args:
+            phone: Phone number or iMessage handle (email)
+
+        Returns:
+            ISO timestamp of the latest message, or None if there are none
+        """
+        logger.info(f"Getting last message date for {phone}")
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return None
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cursor.execute("""
+                SELECT MAX(m.date)
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+            """, (handle_like_pattern(phone),))
+            last_cocoa = cursor.fetchone()[0]
+
+            conn.close()
+
+            if not last_cocoa:
+                return None
+            return parse_timestamp_flexible(last_cocoa).isoformat()
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return None
+        except Exception as e:
+            logger.error(f"Error getting last message date: {e}")
+            return None
+
+    def contact_reachability(self, phone: str, recency_days: int = 30) -> Dict:
+        """
+        Classify whether a handle is still in active use.
+
+        Statuses:
+            - "active": messages in both directions in the last recency_days
+            - "one_way": messages in only one direction in the last
+              recency_days; usually outbound with no replies, but
+              inbound-only traffic is not mutual either
+            - "inactive": messages exist, but none in the last recency_days
+            - "unknown": no messages with this handle at all
+
+        Args:
+            phone: Phone number or iMessage handle
+            recency_days: Window that counts as recent
+
+        Returns:
+            Dict: {"status", "sent_recent", "received_recent", "recency_days"}
+        """
+        logger.info(f"Checking reachability of {phone} (days: {recency_days})")
+
+        result = {
+            "status": "unknown",
+            "sent_recent": 0,
+            "received_recent": 0,
+            "recency_days": recency_days
+        }
+
+        if not self.messages_db_path.exists():
+            logger.error(f"Messages database not found: {self.messages_db_path}")
+            return result
+
+        try:
+            conn = sqlite3.connect(f"file:{self.messages_db_path}?mode=ro", uri=True)
+            cursor = conn.cursor()
+
+            cutoff_date = datetime.now() - timedelta(days=recency_days)
+            cutoff_cocoa = cocoa_timestamp(cutoff_date)
+
+            cursor.execute("""
+                SELECT
+                    COUNT(*),
+                    SUM(CASE WHEN m.date >= ? AND m.is_from_me = 1 THEN 1 ELSE 0 END),
+                    SUM(CASE WHEN m.date >= ? AND m.is_from_me = 0 THEN 1 ELSE 0 END)
+                FROM message m
+                JOIN handle h ON m.handle_id = h.ROWID
+                WHERE h.id LIKE ?
+                    AND (m.associated_message_type IS NULL OR m.associated_message_type = 0)
+            """, (cutoff_cocoa, cutoff_cocoa, handle_like_pattern(phone)))
+            total, sent, received = cursor.fetchone()
+            conn.close()
+
+            result["sent_recent"] = sent or 0
+            result["received_recent"] = received or 0
+
+            if not total:
+                result["status"] = "unknown"
+            elif result["sent_recent"] and result["received_recent"]:
+                result["status"] = "active"
+            elif result["sent_recent"] or result["received_recent"]:
+                result["status"] = "one_way"
+            else:
+                result["status"] = "inactive"
+
+            return result
+
+        except sqlite3.Error as e:
+            logger.error(f"Database error: {e}")
+            return result
+        except Exception as e:
+            logger.error(f"Error checking reachability: {e}")
+            return result
+
     def search_unknown_senders(
         self,
         known_phones: List[str],
@@ -2642,8 +7324,7 @@ class MessagesInterface:
 
             # Calculate cutoff date in Cocoa timestamp format
             cutoff = datetime.now() - timedelta(days=days)
-            cocoa_epoch = datetime(2001, 1, 1)
-            cutoff_cocoa = (cutoff - cocoa_epoch).total_seconds() * 1_000_000_000
+            cutoff_cocoa = cocoa_timestamp(cutoff)
 
             # First, get all unique handles with message counts
             handles_query = """
@@ -2722,7 +7403,7 @@ class MessagesInterface:
 
                     # Convert Cocoa timestamp
                     if date_cocoa:
-                        msg_date = cocoa_epoch + timedelta(seconds=date_cocoa / 1_000_000_000)
+                        msg_date = parse_timestamp_flexible(date_cocoa)
                     else:
                         msg_date = None
 
@@ -2734,7 +7415,7 @@ class MessagesInterface:
 
                 # Convert last message date
                 if last_date_cocoa:
-                    last_date = cocoa_epoch + timedelta(seconds=last_date_cocoa / 1_000_000_000)
+                    last_date = parse_timestamp_flexible(last_date_cocoa)
                 else:
                     last_date = None
 
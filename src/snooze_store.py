@@ -0,0 +1,93 @@
+"""
+Local follow-up snoozes for the iMessage Gateway.
+
+Records handles whose follow-ups were dismissed (e.g. because you just
+replied) so `followup` can hide them until the snooze expires. Stored as
+JSON next to contacts.json; chat.db itself is never written.
+"""
+
+import json
+import logging
+from datetime import datetime, timedelta
+from pathlib import Path
+from typing import Dict
+
+logger = logging.getLogger(__name__)
+
+
+class SnoozeStore:
+    """Persists follow-up snoozes as {handle: ISO-8601 expiry}."""
+
+    def __init__(self, path: str = "config/followup_snoozes.json"):
+        """
+        Initialize snooze store.
+
+        Args:
+            path: JSON file holding the snoozes (created on first write)
+        """
+        self.path = Path(path)
+        self.snoozes: Dict[str, str] = {}
+        self._load()
+
+    def _load(self):
+        """Load snoozes from disk, starting empty if the file is missing or invalid."""
+        if not self.path.exists():
+            return
+
+        try:
+            with open(self.path) as f:
+                self.snoozes = json.load(f).get("snoozes", {})
+        except (ValueError, AttributeError) as e:
+            logger.error(f"Error loading snoozes: {e}")
+            self.snoozes = {}
+
+    def _save(self):
+        """Write snoozes back to disk."""
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.path, 'w') as f:
+            json.dump({"snoozes": self.snoozes}, f, indent=2)
+
+    def snooze(self, phone: str, days: int = 7) -> str:
+        """
+        Hide follow-ups for a handle for a number of days.
+
+        Args:
+            phone: Phone number or handle, as reported by follow-up detection
+            days: Length of the snooze
+
+        Returns:
+            ISO timestamp when the snooze expires
+        """
+        until = (datetime.now() + timedelta(days=days)).isoformat(timespec="seconds")
+        self.snoozes[phone] = until
+        self._save()
+        logger.info(f"Snoozed follow-ups for {phone} until {until}")
+        return until
+
+    def is_snoozed(self, phone: str) -> bool:
+        """
+        Check whether a handle has an unexpired snooze.
+
+        Handles are compared by digits when both have them, so
+        "+14155551234" matches a snooze recorded for "4155551234".
+        Entries with an unreadable expiry are logged and skipped.
+        """
+        now = datetime.now()
+        digits = ''.join(c for c in phone if c.isdigit())
+
+        for snoozed, until in self.snoozes.items():
+            try:
+                expired = datetime.fromisoformat(until) <= now
+            except (TypeError, ValueError) as e:
+                logger.warning(f"Skipping snooze for {snoozed} with invalid expiry {until!r}: {e}")
+                continue
+            if expired:
+                continue
+            snoozed_digits = ''.join(c for c in snoozed if c.isdigit())
+            if snoozed == phone:
+                return True
+            if digits and snoozed_digits and (
+                    digits.endswith(snoozed_digits) or snoozed_digits.endswith(digits)):
+                return True
+
+        return False
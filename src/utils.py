@@ -0,0 +1,537 @@
+"""
+Shared helpers for the iMessage Gateway.
+
+Small, dependency-free functions used by the CLI and library modules.
+"""
+
+import logging
+import math
+import re
+import subprocess
+import uuid
+import zlib
+from datetime import datetime
+from typing import Any, Callable, Dict, List, Optional, Tuple
+
+logger = logging.getLogger(__name__)
+
+# Characters that commonly appear in formatted phone numbers
+PHONE_FORMATTING_CHARS = set("+-()")
+
+# 2001-01-01 UTC (the Cocoa epoch chat.db counts from) in Unix seconds
+COCOA_EPOCH_UNIX_SECONDS = 978_307_200
+
+# ITU calling codes for the 50 most common countries, as
+# (code, ISO 3166-1 alpha-2). Code 1 is shared by the US, Canada and
+# several Caribbean nations and is reported as "US".
+COUNTRY_CALLING_CODES = (
+    (1, "US"), (7, "RU"), (20, "EG"), (27, "ZA"), (30, "GR"),
+    (31, "NL"), (32, "BE"), (33, "FR"), (34, "ES"), (36, "HU"),
+    (39, "IT"), (40, "RO"), (41, "CH"), (43, "AT"), (44, "GB"),
+    (45, "DK"), (46, "SE"), (47, "NO"), (48, "PL"), (49, "DE"),
+    (51, "PE"), (52, "MX"), (53, "CU"), (54, "AR"), (55, "BR"),
+    (56, "CL"), (57, "CO"), (58, "VE"), (60, "MY"), (61, "AU"),
+    (62, "ID"), (63, "PH"), (64, "NZ"), (65, "SG"), (66, "TH"),
+    (81, "JP"), (82, "KR"), (84, "VN"), (86, "CN"), (90, "TR"),
+    (91, "IN"), (92, "PK"), (94, "LK"), (98, "IR"), (234, "NG"),
+    (351, "PT"), (353, "IE"), (852, "HK"), (971, "AE"), (972, "IL"),
+)
+
+# Partial block characters for bar charts, in eighths of a cell
+BAR_EIGHTHS = " ▏▎▍▌▋▊▉"
+
+# Heatmap cell shades, from empty to busiest
+HEATMAP_SHADES = " ░▒▓█"
+
+# ANSI foreground colors used for per-sender highlighting
+# (red, green, yellow, blue, magenta, cyan)
+SENDER_COLORS = (31, 32, 33, 34, 35, 36)
+
+
+def is_likely_phone_number(value: str) -> bool:
+    """
+    Guess whether user input is a phone number rather than a contact name.
+
+    Used to route CLI arguments like "415-555-1234" straight to phone-based
+    queries instead of fuzzy-matching them against contact names.
+
+    Args:
+        value: Raw user input
+
+    Returns:
+        True if the input contains phone formatting (+, -, parentheses) and
+        at least 7 digits, or consists solely of 10 or more digits
+
+    Examples:
+        "415-555-1234" -> True
+        "+1 (415) 555-1234" -> True
+        "4155551234" -> True
+        "Jean-Luc" -> False
+    """
+    if not value:
+        return False
+
+    value = value.strip()
+    digit_count = sum(c.isdigit() for c in value)
+
+    if value.isdigit():
+        return digit_count >= 10
+
+    if any(c in PHONE_FORMATTING_CHARS for c in value):
+        return digit_count >= 7 and not re.search(r'[A-Za-z@]', value)
+
+    return False
+
+
+def format_bytes(num_bytes: int) -> str:
+    """
+    Format a byte count as a human-readable size.
+
+    Uses 1024-based units. Values under 10 of a unit get two decimal places,
+    larger values one, so small sizes keep useful precision.
+
+    Args:
+        num_bytes: Size in bytes
+
+    Returns:
+        Size string, e.g. "512 B", "1.20 MB", "834.0 KB", "3.70 GB"
+    """
+    size = float(num_bytes or 0)
+    for unit in ("B", "KB", "MB", "GB", "TB"):
+        if size < 1024 or unit == "TB":
+            break
+        size /= 1024
+
+    if unit == "B":
+        return f"{int(size)} B"
+    if size < 10:
+        return f"{size:.2f} {unit}"
+    return f"{size:.1f} {unit}"
+
+
+def parse_timestamp_flexible(value: Optional[int]) -> Optional[datetime]:
+    """
+    Convert a chat.db timestamp stored in any of the known formats.
+
+    Newer macOS versions store Cocoa nanoseconds, older ones and some
+    export tools store seconds. The format is picked by magnitude:
+        - above 1_000_000_000_000: Cocoa nanoseconds
+        - above 978_307_200 (2001-01-01 in Unix seconds): Cocoa seconds
+        - otherwise: Unix epoch seconds
+
+    Args:
+        value: Raw timestamp column value
+
+    Returns:
+        Naive datetime in local time, or None for a missing or zero timestamp
+    """
+    if not value:
+        return None
+
+    if value > 1_000_000_000_000:
+        unix_seconds = value / 1_000_000_000 + COCOA_EPOCH_UNIX_SECONDS
+    elif value > COCOA_EPOCH_UNIX_SECONDS:
+        unix_seconds = value + COCOA_EPOCH_UNIX_SECONDS
+    else:
+        unix_seconds = value
+    return datetime.fromtimestamp(unix_seconds)
+
+
+def cocoa_timestamp(moment: datetime) -> int:
+    """
+    Convert a local datetime to a chat.db timestamp.
+
+    The inverse of parse_timestamp_flexible for current databases, for
+    comparing against message.date in SQL. Naive datetimes are taken as
+    local time, like datetime.now().
+
+    Args:
+        moment: Datetime to convert
+
+    Returns:
+        Cocoa nanoseconds (nanoseconds since 2001-01-01 UTC)
+    """
+    return int((moment.timestamp() - COCOA_EPOCH_UNIX_SECONDS) * 1_000_000_000)
+
+
+def phone_country_code(digits: str) -> Optional[str]:
+    """
+    Look up the country of a phone number from its calling code.
+
+    A lightweight answer to "is this a US number?" without a full phone
+    number library. Only the codes in COUNTRY_CALLING_CODES are known.
+
+    Args:
+        digits: Normalized number with country code, e.g. "14155551234"
+            or "+447911123456" (a leading + and formatting are ignored)
+
+    Returns:
+        ISO 3166-1 alpha-2 code (e.g. "US", "GB"), or None if unknown
+
+    Examples:
+        "14155551234" -> "US"
+        "+447911123456" -> "GB"
+        "07911123456" -> None (no country code)
+    """
+    digits = "".join(c for c in digits or "" if c.isdigit())
+    if digits.startswith("0"):
+        # Trunk prefix of a national number, not a country code
+        return None
+
+    # Calling codes are prefix-free, so at most one length matches
+    for length in (1, 2, 3):
+        prefix = digits[:length]
+        if len(prefix) < length:
+            break
+        for code, country in COUNTRY_CALLING_CODES:
+            if int(prefix) == code:
+                return country
+    return None
+
+
+def jaro_winkler(a: str, b: str, prefix_scale: float = 0.1) -> float:
+    """
+    Jaro-Winkler similarity of two strings.
+
+    Favours strings that share a prefix, which suits ranking names as a
+    user types them. Comparison is case-sensitive; lowercase both sides
+    for case-insensitive matching.
+
+    Args:
+        a: First string
+        b: Second string
+        prefix_scale: Boost per shared leading character (up to 4)
+
+    Returns:
+        Score from 0.0 (nothing in common) to 1.0 (identical)
+    """
+    if a == b:
+        return 1.0
+    if not a or not b:
+        return 0.0
+
+    window = max(0, max(len(a), len(b)) // 2 - 1)
+    a_matched = [False] * len(a)
+    b_matched = [False] * len(b)
+    matches = 0
+    for i, ch in enumerate(a):
+        for j in range(max(0, i - window), min(len(b), i + window + 1)):
+            if not b_matched[j] and b[j] == ch:
+                a_matched[i] = b_matched[j] = True
+                matches += 1
+                break
+    if not matches:
+        return 0.0
+
+    a_chars = [ch for ch, m in zip(a, a_matched) if m]
+    b_chars = [ch for ch, m in zip(b, b_matched) if m]
+    transpositions = sum(x != y for x, y in zip(a_chars, b_chars)) / 2
+
+    jaro = (matches / len(a) + matches / len(b) + (matches - transpositions) / matches) / 3
+
+    prefix = 0
+    for x, y in zip(a[:4], b[:4]):
+        if x != y:
+            break
+        prefix += 1
+    return jaro + prefix * prefix_scale * (1 - jaro)
+
+
+def summarize_text(text: str, max_chars: int) -> str:
+    """
+    Shorten text for a preview without cutting words in half.
+
+    Cuts at the last whitespace before max_chars and appends "…". A single
+    word longer than max_chars falls back to a hard cut.
+
+    Args:
+        text: Text to shorten
+        max_chars: Maximum characters to keep (0 or less = no limit)
+
+    Returns:
+        The original text, or a word-aligned prefix plus "…"
+    """
+    if not text or max_chars <= 0 or len(text) <= max_chars:
+        return text
+
+    cut = text[:max_chars]
+    if not text[max_chars].isspace():
+        boundary = max(cut.rfind(" "), cut.rfind("\n"), cut.rfind("\t"))
+        if boundary > 0:
+            cut = cut[:boundary]
+    return cut.rstrip() + "…"
+
+
+SENTENCE_ENDINGS = ".!?"
+
+
+def truncate_to_sentence(text: str, max_chars: int) -> str:
+    """
+    Shorten text for a preview, preferring to end on a whole sentence.
+
+    Cuts after the last ".", "!" or "?" that fits within max_chars, as long
+    as that keeps at least a third of the allowed length; a complete
+    sentence needs no ellipsis. Otherwise falls back to summarize_text's
+    word-boundary (and finally character) cut.
+
+    Args:
+        text: Text to shorten
+        max_chars: Maximum characters to keep (0 or less = no limit)
+
+    Returns:
+        The original text, its leading sentences, or a word-aligned prefix plus "…"
+    """
+    if not text or max_chars <= 0 or len(text) <= max_chars:
+        return text
+
+    for i in range(max_chars - 1, max_chars // 3 - 1, -1):
+        if text[i] in SENTENCE_ENDINGS and (i + 1 == len(text) or text[i + 1].isspace()):
+            return text[:i + 1]
+    return summarize_text(text, max_chars)
+
+
+def percentile(sorted_values: List[float], pct: float) -> Optional[float]:
+    """
+    Nearest-rank percentile of an ascending list.
+
+    Args:
+        sorted_values: Samples sorted ascending
+        pct: Percentile in (0, 100]
+
+    Returns:
+        The smallest sample with at least pct% of samples at or below it,
+        or None for an empty list
+    """
+    if not sorted_values:
+        return None
+    rank = max(1, math.ceil(pct / 100 * len(sorted_values)))
+    return sorted_values[rank - 1]
+
+
+def bar_chart(data: List[Tuple[str, int]], width: int = 40) -> str:
+    """
+    Render labelled values as a horizontal ASCII bar chart.
+
+    Bars are scaled so the largest value fills `width` cells, with
+    eighth-block characters for the remainder.
+
+    Args:
+        data: (label, value) pairs, rendered in the given order
+        width: Character width of the longest bar
+
+    Returns:
+        Multi-line chart, e.g. "Mon  ████████▋  87"; empty string for no data
+    """
+    if not data:
+        return ""
+
+    max_value = max(value for _, value in data) or 1
+    label_width = max(len(label) for label, _ in data)
+    value_width = max(len(str(value)) for _, value in data)
+
+    lines = []
+    for label, value in data:
+        eighths = round(value / max_value * width * 8)
+        bar = "█" * (eighths // 8) + BAR_EIGHTHS[eighths % 8].strip()
+        lines.append(f"{label.ljust(label_width)}  {bar.ljust(width)}  {str(value).rjust(value_width)}")
+
+    return "\n".join(lines)
+
+
+def heatmap_chart(grid: List[List[int]], row_labels: List[str]) -> str:
+    """
+    Render a grid of counts as a shaded text heatmap.
+
+    Each cell is two characters wide, shaded relative to the largest value
+    with HEATMAP_SHADES; any non-zero count gets at least the lightest shade.
+
+    Args:
+        grid: Rows of counts, all the same length
+        row_labels: One label per row
+
+    Returns:
+        Multi-line heatmap with a header of column indexes every 3 columns;
+        empty string for no data
+    """
+    if not grid or not grid[0]:
+        return ""
+
+    max_value = max(max(row) for row in grid) or 1
+    label_width = max(len(label) for label in row_labels)
+    levels = len(HEATMAP_SHADES) - 1
+
+    header = "".join(str(i).ljust(6) for i in range(0, len(grid[0]), 3))
+    lines = [" " * (label_width + 1) + header.rstrip()]
+    for label, row in zip(row_labels, grid):
+        cells = ""
+        for value in row:
+            shade = 0 if not value else max(1, round(value / max_value * levels))
+            cells += HEATMAP_SHADES[shade] * 2
+        lines.append(f"{label.ljust(label_width)} {cells}")
+
+    return "\n".join(lines)
+
+
+def group_by_date(
+    items: List[Any],
+    get_date: Callable[[Any], Optional[str]]
+) -> List[Dict[str, Any]]:
+    """
+    Split a sequence into runs of items sharing a calendar date.
+
+    Items keep their order; a new group starts whenever the date changes
+    from the previous item, so the caller decides oldest- or newest-first.
+
+    Args:
+        items: Items to group, e.g. message dicts
+        get_date: Returns an item's ISO timestamp, or None if unknown
+
+    Returns:
+        List of {"date": "YYYY-MM-DD" or None, "items": [...]} dicts
+    """
+    groups: List[Dict[str, Any]] = []
+    for item in items:
+        timestamp = get_date(item)
+        date = timestamp[:10] if timestamp else None
+        if not groups or groups[-1]["date"] != date:
+            groups.append({"date": date, "items": []})
+        groups[-1]["items"].append(item)
+    return groups
+
+
+def escape_applescript_string(s: str) -> str:
+    r"""
+    Escape a string for safe use in AppleScript.
+
+    AppleScript strings use backslash escapes, so we must:
+    1. Escape backslashes first (\ -> \\)
+    2. Then escape double quotes (" -> \")
+
+    This prevents injection attacks where user data could break out of
+    the quoted string context in AppleScript commands.
+
+    Args:
+        s: The string to escape
+
+    Returns:
+        Escaped string safe for AppleScript double-quoted strings
+    """
+    if s is None:
+        return ""
+    # Escape backslashes first, then quotes
+    return s.replace('\\', '\\\\').replace('"', '\\"')
+
+
+def generate_message_id() -> str:
+    """
+    Create a synthetic ID in the same shape as an iMessage GUID.
+
+    For stored drafts and message templates, which need a stable
+    identifier before (or without) being sent, so each such feature
+    doesn't generate its own.
+
+    Returns:
+        Uppercase UUID4, e.g. "3F2504E0-4F89-41D3-9A0C-0305E82C3301"
+    """
+    return str(uuid.uuid4()).upper()
+
+
+def notify(title: str, body: str) -> bool:
+    """
+    Show a macOS notification via AppleScript.
+
+    Args:
+        title: Notification title
+        body: Notification text
+
+    Returns:
+        True if the notification was posted, False otherwise (errors are
+        logged, never raised, so a failed notification can't fail a send)
+    """
+    script = (
+        f'display notification "{escape_applescript_string(body)}" '
+        f'with title "{escape_applescript_string(title)}"'
+    )
+
+    try:
+        result = subprocess.run(
+            ['osascript', '-e', script],
+            capture_output=True,
+            text=True,
+            timeout=10
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        logger.warning(f"Could not show notification: {e}")
+        return False
+
+    if result.returncode != 0:
+        logger.warning(f"Notification failed: {result.stderr.strip()}")
+        return False
+    return True
+
+
+def applescript_list_to_list(output: str) -> List[str]:
+    """
+    Parse a list of strings printed by osascript.
+
+    Handles the source form from `osascript -ss`, e.g.
+    {"Alice, Smith", "Bob Jones"}, where quoted items may contain commas
+    and backslash-escaped quotes. Without -ss osascript prints items bare
+    ("Bob Jones, Carol White"), which can only be split on ", ".
+
+    Args:
+        output: Raw osascript stdout
+
+    Returns:
+        List of item strings (empty for an empty list or blank output)
+    """
+    text = output.strip()
+    if text.startswith("{") and text.endswith("}"):
+        text = text[1:-1].strip()
+    elif '"' not in text:
+        return [item.strip() for item in text.split(", ")] if text else []
+
+    items = []
+    i = 0
+    while i < len(text):
+        if text[i] == '"':
+            item = []
+            i += 1
+            while i < len(text) and text[i] != '"':
+                if text[i] == "\\" and i + 1 < len(text):
+                    i += 1
+                item.append(text[i])
+                i += 1
+            items.append("".join(item))
+            i += 1
+        else:
+            end = text.find(",", i)
+            end = len(text) if end == -1 else end
+            if text[i:end].strip():
+                items.append(text[i:end].strip())
+            i = end
+        # Skip the separator before the next item
+        while i < len(text) and text[i] in ", ":
+            i += 1
+    return items
+
+
+def sender_color(name: str) -> int:
+    """
+    Pick a stable ANSI color code for a sender name.
+
+    Uses CRC32 rather than hash() so the same sender gets the same color
+    across runs.
+
+    Args:
+        name: Sender display name or handle
+
+    Returns:
+        ANSI foreground color code from SENDER_COLORS
+    """
+    return SENDER_COLORS[zlib.crc32(name.encode("utf-8")) % len(SENDER_COLORS)]
+
+
+def colorize(text: str, color: int) -> str:
+    """Wrap text in an ANSI color escape sequence."""
+    return f"\033[{color}m{text}\033[0m"
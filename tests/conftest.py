@@ -0,0 +1,17 @@
+"""
+Shared pytest fixtures.
+"""
+
+import time
+
+import pytest
+
+
+@pytest.fixture
+def los_angeles_tz(monkeypatch):
+    """Run the test in America/Los_Angeles so local time differs from UTC."""
+    monkeypatch.setenv("TZ", "America/Los_Angeles")
+    time.tzset()
+    yield
+    monkeypatch.undo()
+    time.tzset()
@@ -12,7 +12,7 @@ from pathlib import Path
 import sys
 sys.path.insert(0, str(Path(__file__).parent.parent))
 
-from src.contacts_manager import ContactsManager, Contact
+from src.contacts_manager import ContactsManager, Contact, ContactUpdate, SearchCriteria
 
 
 @pytest.fixture
@@ -49,6 +49,7 @@ def test_load_contacts(temp_contacts_file):
     manager = ContactsManager(temp_contacts_file)
 
     assert len(manager.contacts) == 2
+    assert manager.count() == 2
     assert manager.contacts[0].name == "John Doe"
     assert manager.contacts[1].name == "Jane Smith"
 
@@ -161,3 +162,371 @@ def test_contact_to_dict():
     assert contact_dict["phone"] == "+11234567890"
     assert contact_dict["relationship_type"] == "other"
     assert contact_dict["notes"] == "Test note"
+
+
+def test_reload_picks_up_file_changes(temp_contacts_file):
+    """Test reload re-reads contacts edited on disk."""
+    manager = ContactsManager(temp_contacts_file)
+
+    with open(temp_contacts_file) as f:
+        data = json.load(f)
+    data["contacts"].append({"name": "Late Addition", "phone": "+14155550000"})
+    with open(temp_contacts_file, 'w') as f:
+        json.dump(data, f)
+
+    manager.reload()
+
+    assert len(manager.contacts) == 3
+    assert manager.get_contact_by_name("Late Addition") is not None
+
+
+def test_reload_keeps_contacts_on_invalid_file(temp_contacts_file):
+    """Test a malformed file leaves existing contacts untouched."""
+    manager = ContactsManager(temp_contacts_file)
+
+    with open(temp_contacts_file, 'w') as f:
+        f.write("{not valid json")
+
+    with pytest.raises(ValueError):
+        manager.reload()
+
+    assert len(manager.contacts) == 2
+
+
+def test_contacts_added_recently(temp_contacts_file):
+    """Test add_contact timestamps feed recency filtering."""
+    manager = ContactsManager(temp_contacts_file)
+
+    # Loaded contacts have no timestamp and are never "recent"
+    assert manager.contacts_added_recently(7) == []
+
+    manager.add_contact(name="New Person", phone="+14155559999")
+
+    recent = manager.contacts_added_recently(7)
+    assert [c.name for c in recent] == ["New Person"]
+    assert recent[0].added_at is not None
+
+    # Timestamp survives a round trip through the config file
+    reloaded = ContactsManager(temp_contacts_file)
+    assert reloaded.get_contact_by_name("New Person").added_at == recent[0].added_at
+
+
+def test_normalize_all_phones(temp_contacts_file):
+    """Test phone numbers are canonicalized in memory only."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="Email Only", phone="someone@example.com"))
+
+    changes = manager.normalize_all_phones()
+
+    assert changes == [{"name": "Jane Smith", "old_phone": "4155555678", "new_phone": "+14155555678"}]
+    assert manager.get_contact_by_name("John Doe").phone == "+14155551234"
+    assert manager.get_contact_by_name("Jane Smith").phone == "+14155555678"
+    assert manager.get_contact_by_name("Email Only").phone == "someone@example.com"
+
+    # Nothing is written until the caller saves
+    assert ContactsManager(temp_contacts_file).get_contact_by_name("Jane Smith").phone == "4155555678"
+    manager.save()
+    assert ContactsManager(temp_contacts_file).get_contact_by_name("Jane Smith").phone == "+14155555678"
+
+    # Already-normalized numbers are not reported again
+    assert manager.normalize_all_phones() == []
+
+
+def test_apply_country_prefix(temp_contacts_file):
+    """Test national-format numbers get the country code, others are untouched."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="UK Mobile", phone="07911 123456"))
+    manager.contacts.append(Contact(name="US Local", phone="(415) 555-0199"))
+    manager.contacts.append(Contact(name="Email Only", phone="someone@example.com"))
+
+    changes = manager.apply_country_prefix("44")
+
+    assert changes == [{"name": "UK Mobile", "old_phone": "07911 123456", "new_phone": "+447911123456"}]
+    assert manager.get_contact_by_name("John Doe").phone == "+14155551234"
+
+    changes = manager.apply_country_prefix("1")
+
+    assert {c["name"]: c["new_phone"] for c in changes} == {
+        "Jane Smith": "+14155555678",
+        "US Local": "+14155550199",
+    }
+
+    with pytest.raises(ValueError):
+        manager.apply_country_prefix("UK")
+
+
+def test_apply_country_prefix_skips_international_and_odd_lengths(temp_contacts_file):
+    """Test numbers with an international prefix or non-national length are untouched."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts = [
+        Contact(name="Dialled 00", phone="0044 7911 123456"),
+        Contact(name="Dialled 011", phone="011 44 7911 123456"),
+        Contact(name="Short Code", phone="0800 123"),
+        Contact(name="UK Landline", phone="020 7946 0958"),
+    ]
+
+    changes = manager.apply_country_prefix("44")
+
+    assert changes == [{"name": "UK Landline", "old_phone": "020 7946 0958", "new_phone": "+442079460958"}]
+    assert manager.get_contact_by_name("Dialled 00").phone == "0044 7911 123456"
+    assert manager.get_contact_by_name("Dialled 011").phone == "011 44 7911 123456"
+    assert manager.get_contact_by_name("Short Code").phone == "0800 123"
+
+
+def test_parse_applescript_contacts_prefers_mobile():
+    """Test AppleScript output parsing picks the mobile number."""
+    output = (
+        '{"Ann Lee", "_$!<Home>!$_", "(415) 555-0001", '
+        '"Ann Lee", "_$!<Mobile>!$_", "(415) 555-0002", '
+        '"Bo Park", "", "+14155550003", '
+        '"Bo Park", "_$!<Work>!$_", "+14155550004", '
+        '"Park, Bo", "", "+14155550005"}\n'
+    )
+
+    contacts = ContactsManager.parse_applescript_contacts(output)
+
+    assert [(c.name, c.phone) for c in contacts] == [
+        ("Ann Lee", "(415) 555-0002"),
+        ("Bo Park", "+14155550003"),
+        ("Park, Bo", "+14155550005"),
+    ]
+
+
+def test_export_to_applescript_escapes_and_splits_names():
+    """Test the export script splits names and escapes quotes."""
+    script = ContactsManager.export_to_applescript([
+        Contact(name='Ann "Annie" Lee', phone="+14155550001"),
+        Contact(name="Cher", phone="+14155550002"),
+    ])
+
+    assert 'people whose name is "Ann \\"Annie\\" Lee"' in script
+    assert '{first name:"Ann", last name:"\\"Annie\\" Lee"}' in script
+    assert '{first name:"Cher", last name:""}' in script
+    assert '{label:"mobile", value:"+14155550002"}' in script
+    assert script.rstrip().endswith("return added")
+
+
+def test_contacts_without_notes(temp_contacts_file):
+    """Test blank and missing notes are both reported."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="No Notes", phone="+14155550001"))
+    manager.contacts.append(Contact(name="Blank Notes", phone="+14155550002", notes="  "))
+
+    names = [c.name for c in manager.contacts_without_notes()]
+
+    assert names == ["No Notes", "Blank Notes"]
+
+
+def test_contacts_with_email(temp_contacts_file):
+    """Test only contacts with an email are returned and the email round-trips."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.add_contact("Email Person", "+14155550003")
+    manager.contacts[-1].email = "email.person@icloud.com"
+    manager.save()
+
+    reloaded = ContactsManager(temp_contacts_file)
+
+    assert [c.name for c in reloaded.contacts_with_email()] == ["Email Person"]
+    assert reloaded.contacts_with_email()[0].email == "email.person@icloud.com"
+
+
+def test_get_contact_by_handle_matches_emails(temp_contacts_file):
+    """Test email handles match a contact's phone or email, ignoring case."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="Email Handle", phone="Pat@iCloud.com"))
+    manager.contacts[0].email = "john.doe@example.com"
+
+    assert manager.get_contact_by_handle("pat@icloud.com").name == "Email Handle"
+    assert manager.get_contact_by_handle("John.Doe@Example.com").name == "John Doe"
+    assert manager.get_contact_by_handle("+14155555678").name == "Jane Smith"
+    assert manager.get_contact_by_handle("stranger@example.com") is None
+
+
+def test_fuzzy_search_ranked(temp_contacts_file):
+    """Test candidates are ranked by similarity and weak matches are dropped."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="Johnny Walker", phone="+14155550004"))
+
+    ranked = manager.fuzzy_search_ranked("jon")
+
+    assert [c.name for _, c in ranked][:2] == ["John Doe", "Johnny Walker"]
+    assert all(score > 0.5 for score, _ in ranked)
+    assert ranked == sorted(ranked, key=lambda pair: pair[0], reverse=True)
+    assert len(manager.fuzzy_search_ranked("jon", limit=1)) == 1
+    assert manager.fuzzy_search_ranked("zzzz") == []
+
+
+def test_contacts_by_type(temp_contacts_file):
+    """Test relationship type filtering is case-insensitive."""
+    manager = ContactsManager(temp_contacts_file)
+
+    assert [c.name for c in manager.contacts_by_type("Friend")] == ["John Doe"]
+    assert [c.name for c in manager.contacts_by_type("colleague")] == ["Jane Smith"]
+    assert manager.contacts_by_type("family") == []
+
+
+def test_rename_relationship_type(temp_contacts_file):
+    """Test renaming matches case-insensitively and counts only real changes."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="Old Pal", phone="+14155550005", relationship_type="Friend"))
+
+    assert manager.rename_relationship_type("friend", "close friend") == 2
+    assert [c.name for c in manager.contacts_by_type("close friend")] == ["John Doe", "Old Pal"]
+    assert manager.contacts_by_type("friend") == []
+    assert manager.rename_relationship_type("friend", "close friend") == 0
+
+
+def test_load_or_create_creates_empty_file(tmp_path):
+    """Test a missing contacts file is created and then loaded."""
+    path = tmp_path / "config" / "contacts.json"
+
+    manager = ContactsManager.load_or_create(str(path))
+
+    assert manager.contacts == []
+    assert json.loads(path.read_text()) == {"schema_version": 1, "contacts": []}
+
+    manager.add_contact("New Person", "+14155550123")
+    assert len(ContactsManager.load_or_create(str(path)).contacts) == 1
+
+
+def test_load_or_create_rejects_invalid_file(tmp_path):
+    """Test a malformed existing file raises instead of loading empty."""
+    path = tmp_path / "contacts.json"
+    path.write_text("not json")
+
+    with pytest.raises(ValueError):
+        ContactsManager.load_or_create(str(path))
+
+
+def test_load_from_env(monkeypatch, tmp_path):
+    """Test contacts can be injected through the environment."""
+    missing_path = tmp_path / "contacts.json"
+    monkeypatch.setenv("IMESSAGE_CONTACTS_JSON", json.dumps(
+        {"contacts": [{"name": "Env Person", "phone": "+14155550123"}]}
+    ))
+
+    manager = ContactsManager.load_from_env(str(missing_path))
+
+    assert [c.name for c in manager.contacts] == ["Env Person"]
+    assert not missing_path.exists()
+
+
+def test_load_from_env_falls_back_to_file(monkeypatch, temp_contacts_file):
+    """Test the config file is used when the variable is unset."""
+    monkeypatch.delenv("IMESSAGE_CONTACTS_JSON", raising=False)
+
+    manager = ContactsManager.load_from_env(temp_contacts_file)
+
+    assert len(manager.contacts) == 2
+
+
+def test_load_from_env_rejects_invalid_json(monkeypatch):
+    """Test malformed injected contacts raise ValueError."""
+    monkeypatch.setenv("IMESSAGE_CONTACTS_JSON", '[{"phone": "+14155550123"}]')
+
+    with pytest.raises(ValueError):
+        ContactsManager.load_from_env()
+
+
+def test_unique_relationship_types(temp_contacts_file):
+    """Test relationship types are deduplicated and sorted."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="Another Friend", phone="+14155550001", relationship_type="friend"))
+
+    assert manager.unique_relationship_types() == ["colleague", "friend"]
+
+
+def test_search_combines_criteria(temp_contacts_file):
+    """Test search applies every set criterion."""
+    manager = ContactsManager(temp_contacts_file)
+
+    assert [c.name for c in manager.search(SearchCriteria(name_contains="j"))] == ["John Doe", "Jane Smith"]
+    assert [c.name for c in manager.search(SearchCriteria(phone_prefix="415"))] == ["Jane Smith"]
+    assert [c.name for c in manager.search(SearchCriteria(phone_prefix="+1 (415)"))] == ["John Doe"]
+    assert manager.search(SearchCriteria(name_contains="jane", relationship_type="FRIEND")) == []
+    assert len(manager.search(SearchCriteria())) == 2
+
+
+def test_sorted_by_last_contact(temp_contacts_file):
+    """Test contacts are ordered by last message, never-messaged last."""
+    manager = ContactsManager(temp_contacts_file)
+    manager.contacts.append(Contact(name="Quiet Person", phone="+14155550009"))
+
+    class FakeMessages:
+        def last_messaged(self, phone):
+            return {"4155555678": "2026-03-01T09:00:00", "+14155551234": "2026-01-15T18:30:00"}.get(phone)
+
+    results = manager.sorted_by_last_contact(FakeMessages())
+
+    assert [(c.name, d) for c, d in results] == [
+        ("Jane Smith", "2026-03-01T09:00:00"),
+        ("John Doe", "2026-01-15T18:30:00"),
+        ("Quiet Person", None),
+    ]
+
+
+def test_update_contact(temp_contacts_file):
+    """Test update_contact applies set fields, persists, and adds aliases."""
+    manager = ContactsManager(temp_contacts_file)
+
+    assert manager.update_contact("john doe", ContactUpdate(new_notes="Met at work", add_alias="Johnny"))
+    assert not manager.update_contact("Nobody", ContactUpdate(new_notes="x"))
+
+    reloaded = ContactsManager(temp_contacts_file)
+    contact = reloaded.get_contact_by_name("Johnny")
+    assert contact.name == "John Doe"
+    assert contact.phone == "+14155551234"
+    assert contact.notes == "Met at work"
+    assert contact.aliases == ["Johnny"]
+
+
+def test_json_string_round_trip(temp_contacts_file):
+    """Test to_json_string output loads back into identical contacts."""
+    manager = ContactsManager(temp_contacts_file)
+
+    restored = ContactsManager.from_json_string(manager.to_json_string())
+
+    assert [c.to_dict() for c in restored.contacts] == [c.to_dict() for c in manager.contacts]
+    with pytest.raises(ValueError):
+        ContactsManager.from_json_string('{"contacts": [{"name": "No Phone"}]}')
+
+
+def test_import_from_google_contacts_csv(tmp_path):
+    """Test Google CSV rows are merged per name, preferring mobile numbers."""
+    csv_path = tmp_path / "google.csv"
+    csv_path.write_text(
+        "Name,Given Name,Family Name,Phone 1 - Type,Phone 1 - Value,Phone 2 - Type,Phone 2 - Value\n"
+        "Ann Lee,Ann,Lee,Work,+1 415-555-0100,* Mobile,+1 415-555-0101\n"
+        ",Bo,Chen,Home,+1 415-555-0200 ::: +1 415-555-0201,,\n"
+        "Ann Lee,Ann,Lee,Home,+1 415-555-0102,,\n"
+        "No Phone,No,Phone,,,,\n"
+    )
+
+    contacts = ContactsManager.import_from_google_contacts_csv(str(csv_path))
+
+    assert [c.to_dict() for c in contacts] == [
+        {"name": "Ann Lee", "phone": "+1 415-555-0101", "relationship_type": "other",
+         "notes": "Other phones: +1 415-555-0100, +1 415-555-0102"},
+        {"name": "Bo Chen", "phone": "+1 415-555-0200", "relationship_type": "family",
+         "notes": "Other phones: +1 415-555-0201"},
+    ]
+
+
+def test_load_from_env_is_read_only(monkeypatch, temp_contacts_file):
+    """Test injected contacts are never saved over the contacts file."""
+    before = Path(temp_contacts_file).read_text()
+    monkeypatch.setenv("IMESSAGE_CONTACTS_JSON", json.dumps(
+        [{"name": "Env Person", "phone": "+14155550123"}]
+    ))
+
+    manager = ContactsManager.load_from_env(temp_contacts_file)
+
+    assert manager.read_only
+    with pytest.raises(RuntimeError):
+        manager.add_contact("Carol", "+14155550199")
+    with pytest.raises(RuntimeError):
+        manager.update_contact("Env Person", ContactUpdate(new_notes="x"))
+    with pytest.raises(RuntimeError):
+        manager.save()
+    assert [c.name for c in manager.contacts] == ["Env Person"]
+    assert Path(temp_contacts_file).read_text() == before
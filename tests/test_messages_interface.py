@@ -0,0 +1,139 @@
+"""
+Unit tests for MessagesInterface queries against a small chat.db.
+"""
+
+import sqlite3
+from datetime import datetime, timedelta
+from pathlib import Path
+
+import pytest
+
+import sys
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.contacts_manager import ContactsManager
+from src.messages_interface import MessagesInterface, word_match
+from src.utils import cocoa_timestamp
+
+PHONE = "+14155551234"
+
+
+@pytest.fixture
+def chat_db(tmp_path):
+    """Create an empty chat.db with the tables the queries use."""
+    path = tmp_path / "chat.db"
+    conn = sqlite3.connect(path)
+    conn.executescript("""
+        CREATE TABLE handle(ROWID INTEGER PRIMARY KEY, id TEXT, service TEXT, country TEXT);
+        CREATE TABLE message(
+            ROWID INTEGER PRIMARY KEY, text TEXT, attributedBody BLOB, date INTEGER,
+            is_from_me INTEGER, handle_id INTEGER, associated_message_type INTEGER DEFAULT 0
+        );
+        CREATE TABLE chat(ROWID INTEGER PRIMARY KEY, chat_identifier TEXT);
+        INSERT INTO handle(ROWID, id, service) VALUES (1, '+14155551234', 'iMessage');
+    """)
+    conn.commit()
+    conn.close()
+    return path
+
+
+def add_message(path, date: datetime, is_from_me: bool, text: str = "hi"):
+    """Insert a message to PHONE sent at a local datetime."""
+    conn = sqlite3.connect(path)
+    conn.execute(
+        "INSERT INTO message(text, date, is_from_me, handle_id) VALUES (?, ?, ?, 1)",
+        (text, cocoa_timestamp(date), int(is_from_me))
+    )
+    conn.commit()
+    conn.close()
+
+
+def test_handle_stats_timeline_counts_latest_messages(los_angeles_tz, chat_db):
+    """Test the newest rolling window ends now, not hours ago west of UTC."""
+    minute_ago = datetime.now() - timedelta(minutes=1)
+    add_message(chat_db, minute_ago, is_from_me=False)
+    add_message(chat_db, minute_ago + timedelta(seconds=10), is_from_me=True)
+
+    timeline = MessagesInterface(str(chat_db)).handle_stats_timeline(PHONE, bucket_days=7, buckets=2)
+
+    assert (timeline[0]["sent"], timeline[0]["received"]) == (1, 1)
+    assert (timeline[1]["sent"], timeline[1]["received"]) == (0, 0)
+
+
+def test_stats_timeline_buckets_by_local_day(los_angeles_tz, chat_db):
+    """Test an evening message stays in its local calendar day."""
+    today = datetime.now().replace(hour=0, minute=0, second=0, microsecond=0)
+    add_message(chat_db, today - timedelta(hours=4), is_from_me=False)
+
+    timeline = MessagesInterface(str(chat_db)).stats_timeline(PHONE, period="daily", periods=3)
+
+    assert [p["label"] for p in timeline][-2:] == [
+        (today - timedelta(days=1)).date().isoformat(), today.date().isoformat()
+    ]
+    assert [p["received"] for p in timeline] == [0, 1, 0]
+
+
+def test_sentiment_trend_buckets_by_local_day(los_angeles_tz, chat_db):
+    """Test sentiment periods use the same local days as stats_timeline."""
+    today = datetime.now().replace(hour=0, minute=0, second=0, microsecond=0)
+    # The evening before the first period, which must not be counted
+    add_message(chat_db, today - timedelta(days=2, hours=4), is_from_me=False, text="love it")
+    add_message(chat_db, today - timedelta(days=1, hours=4), is_from_me=False, text="great news, thanks")
+    add_message(chat_db, today - timedelta(hours=4), is_from_me=False, text="this is awful")
+
+    trend = MessagesInterface(str(chat_db)).sentiment_trend(PHONE, period="daily", periods=3)
+
+    assert [p["message_count"] for p in trend] == [1, 1, 0]
+    assert [p["positive_ratio"] for p in trend] == [1.0, 0.0, None]
+
+
+def test_global_summary_counts_today_from_local_midnight(los_angeles_tz, chat_db):
+    """Test last night's messages are not counted as today's."""
+    today = datetime.now().replace(hour=0, minute=0, second=0, microsecond=0)
+    add_message(chat_db, today - timedelta(hours=4), is_from_me=False)
+    add_message(chat_db, today + timedelta(seconds=1), is_from_me=True)
+
+    summary = MessagesInterface(str(chat_db)).global_summary()
+
+    assert summary["total_messages"] == 2
+    assert summary["messages_today"] == 1
+
+
+def test_full_analytics_bundle_uses_local_time(los_angeles_tz, chat_db):
+    """Test per_hour and streaks agree with the local-time heatmap."""
+    today = datetime.now().replace(hour=0, minute=0, second=0, microsecond=0)
+    add_message(chat_db, today - timedelta(days=2, hours=4), is_from_me=False)
+    add_message(chat_db, today - timedelta(days=1, hours=4), is_from_me=True)
+    mi = MessagesInterface(str(chat_db))
+
+    bundle = mi.full_analytics_bundle(PHONE, ContactsManager.from_json_string("[]", config_path=None))
+    heatmap = mi.activity_heatmap(PHONE)
+
+    assert [h["count"] for h in bundle["per_hour"]] == [sum(row[h] for row in heatmap) for h in range(24)]
+    assert bundle["per_hour"][20]["count"] == 2
+    assert bundle["streak"]["longest_days"] == 2
+    assert bundle["streak"]["longest_start"] == (today - timedelta(days=3)).date().isoformat()
+
+
+def test_word_match_phrases():
+    """Test whole-word search matches phrases as consecutive words."""
+    assert word_match("lets have a team meeting today", "team meeting")
+    assert word_match("Team, meeting? yes", "team meeting")
+    assert not word_match("team meetings today", "team meeting")
+    assert not word_match("meeting with the team", "team meeting")
+    assert not word_match("anything", " ? ")
+
+
+def test_contact_reachability_requires_mutual_messages(chat_db):
+    """Test only two-way recent traffic counts as active."""
+    mi = MessagesInterface(str(chat_db))
+    assert mi.contact_reachability(PHONE)["status"] == "unknown"
+
+    add_message(chat_db, datetime.now() - timedelta(days=60), is_from_me=True)
+    assert mi.contact_reachability(PHONE)["status"] == "inactive"
+
+    add_message(chat_db, datetime.now() - timedelta(days=2), is_from_me=False)
+    assert mi.contact_reachability(PHONE)["status"] == "one_way"
+
+    add_message(chat_db, datetime.now() - timedelta(days=1), is_from_me=True)
+    assert mi.contact_reachability(PHONE)["status"] == "active"
@@ -0,0 +1,44 @@
+"""
+Unit tests for follow-up snoozes.
+"""
+
+import json
+from datetime import datetime, timedelta
+from pathlib import Path
+
+import sys
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.snooze_store import SnoozeStore
+
+
+def test_snooze_persists_and_matches_formatting(tmp_path):
+    """Test snoozes survive reloads and match by digits."""
+    path = tmp_path / "snoozes.json"
+
+    SnoozeStore(str(path)).snooze("(415) 555-1234", days=7)
+    store = SnoozeStore(str(path))
+
+    assert store.is_snoozed("+14155551234")
+    assert not store.is_snoozed("+14155559999")
+    assert not store.is_snoozed("someone@example.com")
+
+
+def test_expired_snooze_is_ignored(tmp_path):
+    """Test snoozes stop applying once they expire."""
+    path = tmp_path / "snoozes.json"
+    expired = (datetime.now() - timedelta(hours=1)).isoformat()
+    path.write_text(json.dumps({"snoozes": {"+14155551234": expired}}))
+
+    assert not SnoozeStore(str(path)).is_snoozed("+14155551234")
+
+
+def test_invalid_expiry_is_skipped(tmp_path):
+    """Test a hand-edited, unparseable expiry doesn't break other snoozes."""
+    path = tmp_path / "snoozes.json"
+    active = (datetime.now() + timedelta(days=1)).isoformat()
+    path.write_text(json.dumps({"snoozes": {"+14155551234": "next week", "+14155555678": active}}))
+    store = SnoozeStore(str(path))
+
+    assert not store.is_snoozed("+14155551234")
+    assert store.is_snoozed("+14155555678")
@@ -0,0 +1,185 @@
+"""
+Unit tests for shared gateway helpers.
+"""
+
+import re
+from datetime import datetime
+from pathlib import Path
+
+import sys
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.utils import (
+    applescript_list_to_list, cocoa_timestamp, escape_applescript_string, is_likely_phone_number, format_bytes, parse_timestamp_flexible, bar_chart, percentile,
+    generate_message_id, group_by_date, heatmap_chart, jaro_winkler, phone_country_code, sender_color, summarize_text,
+    truncate_to_sentence, SENDER_COLORS
+)
+
+
+class TestIsLikelyPhoneNumber:
+    """Test phone number input detection."""
+
+    def test_formatted_numbers(self):
+        assert is_likely_phone_number("415-555-1234")
+        assert is_likely_phone_number("+1 (415) 555-1234")
+        assert is_likely_phone_number("+447911123456")
+
+    def test_plain_digits(self):
+        assert is_likely_phone_number("4155551234")
+        assert not is_likely_phone_number("5551234")
+
+    def test_names_are_not_phones(self):
+        assert not is_likely_phone_number("John")
+        assert not is_likely_phone_number("Jean-Luc")
+        assert not is_likely_phone_number("alice@example.com")
+        assert not is_likely_phone_number("")
+
+
+def test_format_bytes():
+    """Test human-readable byte sizes."""
+    assert format_bytes(0) == "0 B"
+    assert format_bytes(512) == "512 B"
+    assert format_bytes(2048) == "2.00 KB"
+    assert format_bytes(834 * 1024) == "834.0 KB"
+    assert format_bytes(int(1.2 * 1024 ** 2)) == "1.20 MB"
+    assert format_bytes(5 * 1024 ** 3) == "5.00 GB"
+
+
+def test_parse_timestamp_flexible(los_angeles_tz):
+    """Test timestamp format detection by magnitude, converted to local time."""
+    # 2024-01-01 00:00 UTC is 16:00 the day before in Los Angeles
+    assert parse_timestamp_flexible(725_760_000 * 1_000_000_000) == datetime(2023, 12, 31, 16)
+    assert parse_timestamp_flexible(1_000_000_000) == datetime(2032, 9, 8, 18, 46, 40)
+    assert parse_timestamp_flexible(946_684_800) == datetime(1999, 12, 31, 16)
+    assert parse_timestamp_flexible(0) is None
+    assert parse_timestamp_flexible(None) is None
+
+
+def test_cocoa_timestamp(los_angeles_tz):
+    """Test local datetimes convert back to Cocoa nanoseconds."""
+    assert cocoa_timestamp(datetime(2023, 12, 31, 16)) == 725_760_000 * 1_000_000_000
+    value = 725_760_123 * 1_000_000_000
+    assert cocoa_timestamp(parse_timestamp_flexible(value)) == value
+
+
+def test_escape_applescript_string():
+    """Test backslashes are escaped before quotes."""
+    assert escape_applescript_string('say "hi" \\o/') == 'say \\"hi\\" \\\\o/'
+    assert escape_applescript_string(None) == ""
+
+
+def test_applescript_list_to_list():
+    """Test osascript list output with quoted commas and escapes."""
+    assert applescript_list_to_list('{"Alice, Smith", "Bob Jones", "Carol White"}\n') == [
+        "Alice, Smith", "Bob Jones", "Carol White"
+    ]
+    assert applescript_list_to_list('{"say \\"hi\\"", "back\\\\slash", 42}') == [
+        'say "hi"', "back\\slash", "42"
+    ]
+    assert applescript_list_to_list("Bob Jones, Carol White") == ["Bob Jones", "Carol White"]
+    assert applescript_list_to_list("{}") == []
+    assert applescript_list_to_list("") == []
+
+
+def test_sender_color_is_stable():
+    """Test the same sender always maps to the same color."""
+    assert sender_color("Alice") == sender_color("Alice")
+    assert sender_color("Alice") in SENDER_COLORS
+
+
+def test_bar_chart():
+    """Test bars scale to the largest value and align labels."""
+    chart = bar_chart([("Mon", 80), ("Tuesday", 10), ("Wed", 0)], width=8)
+
+    assert chart.splitlines() == [
+        "Mon      ████████  80",
+        "Tuesday  █         10",
+        "Wed                 0",
+    ]
+    assert bar_chart([]) == ""
+
+
+def test_heatmap_chart():
+    """Test cells are shaded relative to the busiest cell."""
+    chart = heatmap_chart([[0, 1, 4], [2, 0, 0]], ["Sun", "Mon"])
+
+    assert chart.splitlines() == [
+        "    0",
+        "Sun   ░░██",
+        "Mon ▒▒    ",
+    ]
+    assert heatmap_chart([], []) == ""
+
+
+def test_generate_message_id():
+    """Test IDs match the iMessage GUID format and are unique."""
+    message_id = generate_message_id()
+
+    assert re.fullmatch(r"[0-9A-F]{8}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{12}", message_id)
+    assert generate_message_id() != message_id
+
+
+def test_group_by_date():
+    """Test consecutive items with the same date share a group."""
+    items = [
+        {"date": "2024-01-15T09:00:00"},
+        {"date": "2024-01-15T18:30:00"},
+        {"date": None},
+        {"date": "2024-01-16T08:00:00"},
+    ]
+
+    groups = group_by_date(items, lambda m: m["date"])
+
+    assert [(g["date"], len(g["items"])) for g in groups] == [
+        ("2024-01-15", 2),
+        (None, 1),
+        ("2024-01-16", 1),
+    ]
+
+
+def test_summarize_text_cuts_at_word_boundary():
+    """Test previews end on a whole word."""
+    assert summarize_text("meeting moved to Tuesday afternoon", 20) == "meeting moved to…"
+    assert summarize_text("meeting moved to Tuesday", 16) == "meeting moved to…"
+    assert summarize_text("supercalifragilistic", 5) == "super…"
+    assert summarize_text("short", 20) == "short"
+    assert summarize_text("no limit here", 0) == "no limit here"
+
+
+def test_truncate_to_sentence_prefers_sentence_end():
+    """Test previews end on a sentence, then a word, then a character."""
+    assert truncate_to_sentence("Running late. Be there by six, save me a seat", 30) == "Running late."
+    assert truncate_to_sentence("Sure! Did you get the notes from today?", 25) == "Sure! Did you get the…"
+    assert truncate_to_sentence("meeting moved to Tuesday afternoon", 20) == "meeting moved to…"
+    assert truncate_to_sentence("version 2.5 ships soon", 12) == "version 2.5…"
+    assert truncate_to_sentence("supercalifragilistic", 5) == "super…"
+    assert truncate_to_sentence("Short one.", 20) == "Short one."
+
+
+def test_percentile_nearest_rank():
+    """Test nearest-rank percentiles pick actual samples."""
+    values = [15, 20, 35, 40, 50]
+
+    assert percentile(values, 30) == 20
+    assert percentile(values, 50) == 35
+    assert percentile(values, 100) == 50
+    assert percentile([7], 95) == 7
+    assert percentile([], 50) is None
+
+
+def test_jaro_winkler():
+    """Test similarity scores, including the textbook MARTHA/MARHTA pair."""
+    assert jaro_winkler("martha", "martha") == 1.0
+    assert round(jaro_winkler("martha", "marhta"), 3) == 0.961
+    assert jaro_winkler("abc", "xyz") == 0.0
+    assert jaro_winkler("", "abc") == 0.0
+    assert jaro_winkler("jon", "john") > jaro_winkler("jon", "jane")
+
+
+def test_phone_country_code():
+    """Test calling codes of each length resolve to ISO country codes."""
+    assert phone_country_code("14155551234") == "US"
+    assert phone_country_code("+44 7911 123456") == "GB"
+    assert phone_country_code("+353 87 123 4567") == "IE"
+    assert phone_country_code("07911123456") is None
+    assert phone_country_code("") is None